@@ -0,0 +1,50 @@
+use std::env;
+use std::fs;
+use std::process;
+use angel_font_file_parser::{parse_file, AtlasDimensions};
+
+/// Converts a font file into Rust source code that can be added to a program with include!, so that
+/// the font does not have to be parsed when the program runs. Usually done from a build script:
+///
+/// cargo run --example codegen -- arial.fnt 512 512 src/arial_glyphs.rs
+///
+/// The program then includes the generated constants with:
+///
+/// use angel_font_file_parser::{CharacterInfo, KerningPair, Uv};
+/// include!("arial_glyphs.rs");
+fn main()
+{
+    let arguments = env::args().skip(1).collect::<Vec<String>>();
+
+    if arguments.len() != 4
+    {
+        eprintln!("Usage: codegen <font file> <atlas width> <atlas height> <output file>");
+        process::exit(1);
+    }
+
+    let atlas_dimensions = match (arguments[1].parse(), arguments[2].parse())
+    {
+        (Ok(width), Ok(height)) => AtlasDimensions { width, height },
+        _ =>
+            {
+                eprintln!("The atlas width and height must be numbers");
+                process::exit(1);
+            }
+    };
+
+    let font = match parse_file(&arguments[0], atlas_dimensions)
+    {
+        Ok(font) => font,
+        Err(err) =>
+            {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+    };
+
+    if let Err(err) = fs::write(&arguments[3], font.to_rust_source())
+    {
+        eprintln!("Unable to write {}: {}", arguments[3], err);
+        process::exit(1);
+    }
+}
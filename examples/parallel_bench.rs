@@ -0,0 +1,49 @@
+use std::env;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+use angel_font_file_parser::{parse_parallel, parse_str, AtlasDimensions};
+use angel_font_file_parser::testing::synthetic_font;
+
+/// How many times each way of parsing is timed, of which the fastest time is printed
+const RUNS: usize = 10;
+
+/// Times parse_parallel against parse_str on a synthetic font, printing the number of threads used
+/// along with the fastest time of each. The number of characters can be given, and defaults to
+/// 100,000. RAYON_NUM_THREADS limits the threads used by parse_parallel:
+///
+/// cargo run --release --example parallel_bench --features rayon,testing -- 100000
+fn main()
+{
+    let glyph_count = match env::args().nth(1).map(|x| x.parse::<usize>())
+    {
+        None => 100_000,
+        Some(Ok(glyph_count)) => glyph_count,
+        Some(Err(_)) =>
+            {
+                eprintln!("Usage: parallel_bench [number of characters]");
+                process::exit(1);
+            }
+    };
+
+    let contents = synthetic_font(glyph_count, false);
+    let atlas_dimensions = AtlasDimensions { width: 512, height: 512 };
+
+    let single_thread = fastest(|| { parse_str(&contents, atlas_dimensions).unwrap(); });
+    let parallel = fastest(|| { parse_parallel(&contents, atlas_dimensions).unwrap(); });
+
+    println!("{} characters, {} available cores, {} rayon threads", glyph_count, thread::available_parallelism().map_or(1, |x| x.get()), rayon::current_num_threads());
+    println!("parse_str:      {:?}", single_thread);
+    println!("parse_parallel: {:?}", parallel);
+}
+
+/// Runs the function the given number of times, returning the fastest time it took
+fn fastest<F: FnMut()>(mut function: F) -> Duration
+{
+    (0..RUNS).map(|_|
+        {
+            let start = Instant::now();
+            function();
+            start.elapsed()
+        }).min().unwrap()
+}
@@ -0,0 +1,51 @@
+/* The functions of the ffi feature of angel_font_file_parser, for use from C and C++. Build the
+ * library with `cargo build --release --features ffi` and link against the libangel_font_file_parser
+ * shared or static library in target/release */
+
+#ifndef ANGEL_FONT_FILE_PARSER_H
+#define ANGEL_FONT_FILE_PARSER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The value used by CharacterInfoC for parameters that are missing or could not be read */
+#define ANGEL_FONT_ABSENT INT32_MIN
+
+/* The information of a character. Parameters that are missing or could not be read have the value
+ * ANGEL_FONT_ABSENT */
+typedef struct CharacterInfoC
+{
+    int32_t id;
+    int32_t x;
+    int32_t y;
+    int32_t width;
+    int32_t height;
+    int32_t x_offset;
+    int32_t y_offset;
+    int32_t x_advance;
+    int32_t page;
+    int32_t chnl;
+    /* Non-zero if the character was rotated 90 degrees clockwise to fit into the atlas */
+    int32_t rot;
+    /* The texture coordinates as (u, v) pairs, in the order bottom left, top left, top right and
+     * bottom right */
+    float texture_coordinates[4][2];
+} CharacterInfoC;
+
+/* Reads the characters of the font file at the given UTF-8 location. The number of characters is
+ * written to character_count. Returns null if the file could not be read. The returned characters
+ * must be freed with angel_font_free_characters */
+CharacterInfoC *angel_font_parse(const char *file_location, int32_t atlas_width, int32_t atlas_height, size_t *character_count);
+
+/* Frees the characters returned by angel_font_parse. Null is ignored */
+void angel_font_free_characters(CharacterInfoC *characters, size_t character_count);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
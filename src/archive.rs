@@ -0,0 +1,109 @@
+use std::io::{self, Read, Seek};
+use ::zip::ZipArchive;
+use crate::{decode_and_parse, AtlasDimensions, Font, PageInfo, ParseError, ParseOptions};
+
+/// Reads all of the information supported by this library from a font file stored inside of a zip
+/// archive, such as a bundle of the font file and its page images
+///
+/// `archive` - the contents of the zip archive, such as an opened file
+/// `fnt_name` - the name of the font file entry within the archive, such as "fonts/arial.fnt"
+pub fn parse_from_zip<R: Read + Seek>(archive: R, fnt_name: &str, atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    let data = read_entry(archive, fnt_name)?;
+    decode_and_parse(&data, atlas_dimensions, &ParseOptions::default(), &mut Vec::new())
+}
+
+/// Reads the image of the given page from the same zip archive as its font file. The page file is
+/// resolved relative to the folder of the font file entry, the same as it is for a font on disk
+///
+/// `archive` - the contents of the zip archive, such as an opened file
+/// `fnt_name` - the name of the font file entry within the archive that the page was read from
+/// `page` - the page whose image is read
+pub fn read_page_from_zip<R: Read + Seek>(archive: R, fnt_name: &str, page: &PageInfo) -> Result<Vec<u8>, ParseError>
+{
+    read_entry(archive, &page.archive_entry_name(fnt_name))
+}
+
+impl PageInfo
+{
+    /// Finds the name of the entry of the page image within a zip archive, given the name of the
+    /// entry of the font file it belongs to. Entries always use '/' as the separator, so any '\'
+    /// written by Windows tools is replaced, and "." and ".." folders are removed
+    ///
+    /// `fnt_name` - the name of the font file entry within the archive
+    pub fn archive_entry_name(&self, fnt_name: &str) -> String
+    {
+        let mut folders = fnt_name.split('/').collect::<Vec<&str>>();
+        // The last part is the name of the font file itself
+        folders.pop();
+
+        let file = self.file.replace('\\', "/");
+
+        for part in file.split('/')
+        {
+            match part
+            {
+                "" | "." => {},
+                ".." =>
+                {
+                    folders.pop();
+                },
+                _ => folders.push(part),
+            }
+        }
+
+        folders.join("/")
+    }
+}
+
+/// Reads all of the contents of the named entry of the zip archive
+///
+/// `name` - the name of the entry within the archive
+fn read_entry<R: Read + Seek>(archive: R, name: &str) -> Result<Vec<u8>, ParseError>
+{
+    let open_error = |error: io::Error| ParseError::FileOpen { file_location: name.to_string(), error };
+
+    let mut archive = ZipArchive::new(archive).map_err(|err| open_error(io::Error::other(err)))?;
+    let mut entry = archive.by_name(name).map_err(|err| open_error(io::Error::other(err)))?;
+
+    let mut data = Vec::new();
+    entry.read_to_end(&mut data).map_err(open_error)?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use std::fs::File;
+    use crate::{AtlasDimensions, parse_file, parse_from_zip, read_page_from_zip, PageInfo, ParseError};
+
+    #[test]
+    fn check_parse_from_zip()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_folder = env::current_dir().unwrap().join("test_files");
+        let archive = File::open(test_folder.join("font_bundle.zip")).unwrap();
+
+        let font = parse_from_zip(&archive, "fonts/arial.fnt", atlas_dimensions).unwrap();
+        let on_disk = parse_file(test_folder.join("kerning.fnt"), atlas_dimensions).unwrap();
+        assert_eq!(on_disk.characters, font.characters);
+        assert_eq!(on_disk.kernings, font.kernings);
+
+        // The page of the bundled font is in a sub folder, written with a Windows separator
+        let page = read_page_from_zip(&archive, "fonts/arial.fnt", &font.pages[0]).unwrap();
+        assert_eq!(std::fs::read(test_folder.join("page_image.png")).unwrap(), page);
+
+        assert!(matches!(parse_from_zip(&archive, "fonts/missing.fnt", atlas_dimensions), Err(ParseError::FileOpen { .. })));
+    }
+
+    #[test]
+    fn check_archive_entry_name()
+    {
+        let page = |file: &str| PageInfo{ id: 0, file: file.to_string() };
+
+        assert_eq!("arial.png", page("arial.png").archive_entry_name("arial.fnt"));
+        assert_eq!("fonts/textures/arial.png", page("textures\\arial.png").archive_entry_name("fonts/arial.fnt"));
+        assert_eq!("images/arial.png", page("../images/./arial.png").archive_entry_name("fonts/arial.fnt"));
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+use crate::{decode_and_parse, AtlasDimensions, Font, ParseError, ParseOptions};
+
+/// Reads all of the information of the passed in font file that is supported by this library,
+/// without blocking while the file is read. Only reading the file is asynchronous; the contents
+/// are parsed the same as parse_file once they have been read
+///
+/// `file_location` - the location of the file in the angel file format
+pub async fn parse_async<A: AsRef<Path>>(file_location: A, atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    let file_location = file_location.as_ref();
+
+    match tokio::fs::read(file_location).await
+    {
+        Ok(data) => decode_and_parse(&data, atlas_dimensions, &ParseOptions::default(), &mut Vec::new()),
+        Err(err) => Err(ParseError::FileOpen { file_location: format!("{:?}", file_location), error: err })
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, parse_async, parse_file, ParseError};
+
+    #[tokio::test]
+    async fn check_parse_async()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+
+        let font = parse_async(&test_file, atlas_dimensions).await.unwrap();
+        assert_eq!(parse_file(&test_file, atlas_dimensions).unwrap(), font);
+
+        let missing_file = env::current_dir().unwrap().join("test_files").join("does_not_exist.fnt");
+        assert!(matches!(parse_async(missing_file, atlas_dimensions).await, Err(ParseError::FileOpen { .. })));
+    }
+}
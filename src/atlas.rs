@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+use std::path::Path;
+use crate::{parse_file, AtlasDimensions, Font, ParseError};
+
+/// Reads all of the information of the passed in font file that is supported by this library,
+/// finding the size of the texture atlas from the image of the first page. If the image cannot be
+/// read, the size from the common line is used instead
+///
+/// `file_location` - the location of the file in the angel file format
+pub fn parse_file_with_page_dimensions<A: AsRef<Path> + Debug + Clone>(file_location: A) -> Result<(Font, AtlasDimensions), ParseError>
+{
+    // The texture coordinates cannot be found until the pages of the font are known, so they are
+    // found again once the size of the atlas is known
+    let mut font = parse_file(file_location.clone(), AtlasDimensions { width: 1, height: 1 })?;
+
+    let atlas_dimensions = font.pages.iter()
+        .min_by_key(|x| x.id)
+        .and_then(|page| page_dimensions(&page.page_path(file_location.as_ref())))
+        .or_else(|| font.common.atlas_dimensions())
+        .ok_or(ParseError::UnknownAtlasDimensions)?;
+
+    font.recalculate_texture_coords(atlas_dimensions);
+    Ok((font, atlas_dimensions))
+}
+
+impl Font
+{
+    /// Checks that the image of every page is the same size as the given texture atlas, as
+    /// otherwise the texture coordinates found with the atlas do not match the images. Only the
+    /// size of each image is read. Pages whose image cannot be read are not checked
+    ///
+    /// `fnt_path` - the location of the font file, which the page files are relative to
+    /// `atlas_dimensions` - the size of the texture atlas the texture coordinates were found with
+    pub fn validate_page_dimensions(&self, fnt_path: &Path, atlas_dimensions: AtlasDimensions) -> Result<(), ParseError>
+    {
+        for page in &self.pages
+        {
+            if let Some(actual) = page_dimensions(&page.page_path(fnt_path)).filter(|x| *x != atlas_dimensions)
+            {
+                return Err(ParseError::AtlasMismatch { page: page.id, expected: atlas_dimensions, actual });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the size of the image, without decoding the rest of the image
+fn page_dimensions(page_path: &Path) -> Option<AtlasDimensions>
+{
+    let (width, height) = image::image_dimensions(page_path).ok()?;
+    Some(AtlasDimensions { width: i32::try_from(width).ok()?, height: i32::try_from(height).ok()? })
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, parse_file, parse_file_with_page_dimensions, parse_str, ParseError, Uv};
+
+    #[test]
+    fn check_page_image_dimensions()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("page_image.fnt");
+        let (font, atlas_dimensions) = parse_file_with_page_dimensions(test_file).unwrap();
+
+        // The image is 256 by 128 pixels, even though the common line says otherwise
+        assert_eq!(AtlasDimensions{ width: 256, height: 128 }, atlas_dimensions);
+        assert_eq!(Uv(0.25, 0.75), font.characters[0].texture_coordinates[1]);
+    }
+
+    #[test]
+    fn check_validate_page_dimensions()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("page_image.fnt");
+        let font = parse_file(&test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        match font.validate_page_dimensions(&test_file, AtlasDimensions{ width: 512, height: 512 })
+        {
+            Err(ParseError::AtlasMismatch { page, expected, actual }) =>
+                {
+                    assert_eq!(0, page);
+                    assert_eq!(AtlasDimensions{ width: 512, height: 512 }, expected);
+                    assert_eq!(AtlasDimensions{ width: 256, height: 128 }, actual);
+                },
+            result => panic!("Unexpected result {:?}", result)
+        }
+
+        assert!(font.validate_page_dimensions(&test_file, AtlasDimensions{ width: 256, height: 128 }).is_ok());
+
+        // The page image of this font does not exist, so it cannot be checked
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(&test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(font.validate_page_dimensions(&test_file, AtlasDimensions{ width: 1, height: 1 }).is_ok());
+    }
+
+    #[test]
+    fn check_common_line_fallback()
+    {
+        // The page image of this font does not exist
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let (_, atlas_dimensions) = parse_file_with_page_dimensions(test_file).unwrap();
+        assert_eq!(AtlasDimensions{ width: 512, height: 512 }, atlas_dimensions);
+
+        let test_file = env::current_dir().unwrap().join("test_files").join("no_atlas_size.fnt");
+        assert!(matches!(parse_file_with_page_dimensions(test_file), Err(ParseError::UnknownAtlasDimensions)));
+
+        assert!(parse_str("common lineHeight=82", AtlasDimensions{ width: 512, height: 512 }).unwrap().common.atlas_dimensions().is_none());
+    }
+}
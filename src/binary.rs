@@ -0,0 +1,701 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use crate::{add_character, calculate_char_texture_coords, validate_atlas_dimensions, AtlasDimensions, CharacterInfo, CommonInfo, Font, FontInfo, KerningPair, PageInfo, ParseError, ParseOptions, RectEdges};
+
+/// The version of the binary format that is read and written
+const BINARY_VERSION: u8 = 3;
+
+/// The identifiers of each block of the binary format
+const INFO_BLOCK: u8 = 1;
+const COMMON_BLOCK: u8 = 2;
+const PAGES_BLOCK: u8 = 3;
+const CHARS_BLOCK: u8 = 4;
+const KERNING_BLOCK: u8 = 5;
+
+/// The number of bytes used to store a single entry of the blocks that are lists
+const CHAR_SIZE: usize = 20;
+const KERNING_SIZE: usize = 10;
+
+/// The bits of the bit field of the info block
+const SMOOTH_BIT: u8 = 1;
+const UNICODE_BIT: u8 = 1 << 1;
+const ITALIC_BIT: u8 = 1 << 2;
+const BOLD_BIT: u8 = 1 << 3;
+/// The bit of the common block that is set if characters are packed into the channels
+const PACKED_BIT: u8 = 1 << 7;
+
+/// The names used by the text format for each of the character set numbers of the binary format
+const CHARSETS: [(u8, &str); 19] =
+[
+    (0, "ANSI"), (1, "DEFAULT"), (2, "SYMBOL"), (77, "MAC"), (128, "SHIFTJIS"), (129, "HANGUL"),
+    (130, "JOHAB"), (134, "GB2312"), (136, "CHINESEBIG5"), (161, "GREEK"), (162, "TURKISH"),
+    (163, "VIETNAMESE"), (177, "HEBREW"), (178, "ARABIC"), (186, "BALTIC"), (204, "RUSSIAN"),
+    (222, "THAI"), (238, "EASTEUROPE"), (255, "OEM"),
+];
+
+/// Reads all of the information supported by this library from a font in the binary version of the
+/// angel file format
+///
+/// `data` - the complete contents of the binary font file
+pub fn parse_binary(data: &[u8], atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    read_binary(data, atlas_dimensions, &ParseOptions::default())
+}
+
+/// Reads a font in the binary format with the options that apply to it, which is used by the
+/// parse functions that take options when they are given a binary font. Only the rect edges,
+/// duplicate chars and strict options apply, as the values of a binary font are never written as text
+///
+/// `data` - the complete contents of the binary font file
+/// `options` - the options used to read the font
+pub(crate) fn read_binary(data: &[u8], atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<Font, ParseError>
+{
+    validate_atlas_dimensions(atlas_dimensions)?;
+
+    if data.len() < 4 || &data[0..3] != b"BMF"
+    {
+        return Err(ParseError::InvalidBinary("File does not start with the BMF identifier".to_string()));
+    }
+
+    if data[3] != BINARY_VERSION
+    {
+        return Err(ParseError::InvalidBinary(format!("Unsupported binary version {}", data[3])));
+    }
+
+    let mut font = Font::new(atlas_dimensions, options.rect_edges);
+    let mut reader = BinaryReader { data, position: 4 };
+    // The index of the first character with each id, used to merge duplicated characters
+    let mut char_indices = HashMap::new();
+
+    while !reader.is_finished()
+    {
+        let block_type = reader.read_u8()?;
+        let block_size = reader.read_u32()? as usize;
+        let mut block = BinaryReader { data: reader.read_bytes(block_size)?, position: 0 };
+
+        match block_type
+        {
+            INFO_BLOCK =>
+                {
+                    font.info = read_info_block(&mut block)?;
+                    font.stats.info_found = true;
+                },
+            COMMON_BLOCK =>
+                {
+                    font.common = read_common_block(&mut block)?;
+                    font.stats.common_found = true;
+                },
+            PAGES_BLOCK => font.pages = read_pages_block(&mut block)?,
+            CHARS_BLOCK =>
+                {
+                    let characters = read_chars_block(&mut block, atlas_dimensions, options.rect_edges)?;
+                    font.stats.chars += characters.len();
+
+                    for char_info in characters
+                    {
+                        add_character(&mut font, char_info, atlas_dimensions, options, &mut char_indices);
+                    }
+                },
+            KERNING_BLOCK =>
+                {
+                    let kernings = read_kerning_block(&mut block)?;
+                    font.stats.kernings = kernings.len();
+
+                    for kerning in kernings
+                    {
+                        font.add_kerning(kerning);
+                    }
+                },
+            // Unknown blocks are skipped, the same as unknown lines of the text format
+            _ => font.stats.skipped += 1
+        }
+    }
+
+    if options.strict
+    {
+        font.validate_pages()?;
+    }
+
+    font.rebuild_glyph_ids();
+    Ok(font)
+}
+
+impl Font
+{
+    /// Writes the font in the binary version of the angel file format. Parameters without a value
+    /// are written as zero, as the binary format has no way of representing a missing value
+    ///
+    /// `writer` - the destination of the binary font, such as a file
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> io::Result<()>
+    {
+        writer.write_all(b"BMF")?;
+        writer.write_all(&[BINARY_VERSION])?;
+
+        write_block(&mut writer, INFO_BLOCK, &info_block(&self.info)?)?;
+        write_block(&mut writer, COMMON_BLOCK, &common_block(&self.common)?)?;
+        write_block(&mut writer, PAGES_BLOCK, &pages_block(&self.pages)?)?;
+        write_block(&mut writer, CHARS_BLOCK, &chars_block(&self.characters)?)?;
+
+        // Fonts without kerning do not have the kerning block at all
+        if !self.kernings.is_empty()
+        {
+            write_block(&mut writer, KERNING_BLOCK, &kerning_block(&self.sorted_kernings())?)?;
+        }
+
+        writer.flush()
+    }
+}
+
+// *** Reading ***
+
+/// Keeps track of how much of the binary data has been read
+struct BinaryReader<'a>
+{
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BinaryReader<'a>
+{
+    fn is_finished(&self) -> bool
+    {
+        self.position >= self.data.len()
+    }
+
+    fn read_bytes(&mut self, count: usize) -> Result<&'a [u8], ParseError>
+    {
+        let bytes = self.position.checked_add(count).and_then(|end| self.data.get(self.position..end));
+
+        match bytes
+        {
+            Some(bytes) =>
+                {
+                    self.position += count;
+                    Ok(bytes)
+                },
+            None => Err(ParseError::InvalidBinary(format!("Unexpected end of data at byte {}", self.position)))
+        }
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ParseError>
+    {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, ParseError>
+    {
+        let bytes = self.read_bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_i16(&mut self) -> Result<i16, ParseError>
+    {
+        let bytes = self.read_bytes(2)?;
+        Ok(i16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, ParseError>
+    {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads a null terminated string. The null terminator is not part of the result
+    fn read_string(&mut self) -> Result<String, ParseError>
+    {
+        let remaining = &self.data[self.position..];
+
+        match remaining.iter().position(|x| *x == 0)
+        {
+            Some(length) =>
+                {
+                    let string = String::from_utf8_lossy(&remaining[..length]).into_owned();
+                    self.position += length + 1;
+                    Ok(string)
+                },
+            None => Err(ParseError::InvalidBinary("String is missing its null terminator".to_string()))
+        }
+    }
+}
+
+fn read_info_block(block: &mut BinaryReader) -> Result<FontInfo, ParseError>
+{
+    let mut font_info = FontInfo::new();
+
+    font_info.size = Some(block.read_i16()? as i32);
+    let bit_field = block.read_u8()?;
+    font_info.smooth = Some(bit_field & SMOOTH_BIT != 0);
+    font_info.unicode = Some(bit_field & UNICODE_BIT != 0);
+    font_info.italic = Some(bit_field & ITALIC_BIT != 0);
+    font_info.bold = Some(bit_field & BOLD_BIT != 0);
+    let charset = block.read_u8()?;
+
+    // The character set is only meaningful for fonts that are not unicode, which the text format
+    // shows with an empty charset
+    font_info.charset = Some(match font_info.unicode
+    {
+        Some(true) => String::new(),
+        _ => CHARSETS.iter().find(|x| x.0 == charset).map(|x| x.1.to_string()).unwrap_or_else(|| charset.to_string())
+    });
+    font_info.stretch_h = Some(block.read_u16()? as i32);
+    font_info.aa = Some(block.read_u8()? as i32);
+
+    let mut padding = [0; 4];
+    for x in padding.iter_mut()
+    {
+        *x = block.read_u8()? as i32;
+    }
+    font_info.padding = Some(padding);
+
+    let mut spacing = [0; 2];
+    for x in spacing.iter_mut()
+    {
+        *x = block.read_u8()? as i32;
+    }
+    font_info.spacing = Some(spacing);
+
+    font_info.outline = Some(block.read_u8()? as i32);
+    font_info.face = Some(block.read_string()?);
+
+    Ok(font_info)
+}
+
+fn read_common_block(block: &mut BinaryReader) -> Result<CommonInfo, ParseError>
+{
+    let mut common_info = CommonInfo::new();
+
+    common_info.line_height = Some(block.read_u16()? as i32);
+    common_info.base = Some(block.read_u16()? as i32);
+    common_info.scale_w = Some(block.read_u16()? as i32);
+    common_info.scale_h = Some(block.read_u16()? as i32);
+    common_info.pages = Some(block.read_u16()? as i32);
+    common_info.packed = Some((block.read_u8()? & PACKED_BIT != 0) as i32);
+    common_info.alpha_chnl = Some(block.read_u8()? as i32);
+    common_info.red_chnl = Some(block.read_u8()? as i32);
+    common_info.green_chnl = Some(block.read_u8()? as i32);
+    common_info.blue_chnl = Some(block.read_u8()? as i32);
+
+    Ok(common_info)
+}
+
+fn read_pages_block(block: &mut BinaryReader) -> Result<Vec<PageInfo>, ParseError>
+{
+    let mut pages = Vec::new();
+
+    // The id of a page is its position in the block
+    while !block.is_finished()
+    {
+        let file = block.read_string()?;
+        pages.push(PageInfo { id: pages.len() as i32, file });
+    }
+
+    Ok(pages)
+}
+
+fn read_chars_block(block: &mut BinaryReader, atlas_dimensions: AtlasDimensions, rect_edges: RectEdges) -> Result<Vec<CharacterInfo>, ParseError>
+{
+    if !block.data.len().is_multiple_of(CHAR_SIZE)
+    {
+        return Err(ParseError::InvalidBinary(format!("Chars block size {} is not a multiple of {}", block.data.len(), CHAR_SIZE)));
+    }
+
+    let mut characters = Vec::new();
+
+    while !block.is_finished()
+    {
+        let mut char_info = CharacterInfo::new();
+
+        char_info.id = Some(block.read_u32()? as i32);
+        char_info.x = Some(block.read_u16()? as i32);
+        char_info.y = Some(block.read_u16()? as i32);
+        char_info.width = Some(block.read_u16()? as i32);
+        char_info.height = Some(block.read_u16()? as i32);
+        char_info.x_offset = Some(block.read_i16()? as i32);
+        char_info.y_offset = Some(block.read_i16()? as i32);
+        char_info.x_advance = Some(block.read_i16()? as i32);
+        char_info.page = Some(block.read_u8()? as i32);
+        char_info.chnl = Some(block.read_u8()? as i32);
+
+        calculate_char_texture_coords(&mut char_info, atlas_dimensions, rect_edges);
+        characters.push(char_info);
+    }
+
+    Ok(characters)
+}
+
+fn read_kerning_block(block: &mut BinaryReader) -> Result<Vec<KerningPair>, ParseError>
+{
+    if !block.data.len().is_multiple_of(KERNING_SIZE)
+    {
+        return Err(ParseError::InvalidBinary(format!("Kerning block size {} is not a multiple of {}", block.data.len(), KERNING_SIZE)));
+    }
+
+    let mut kernings = Vec::new();
+
+    while !block.is_finished()
+    {
+        let first = block.read_u32()? as i32;
+        let second = block.read_u32()? as i32;
+        let amount = block.read_i16()? as i32;
+        kernings.push(KerningPair { first, second, amount });
+    }
+
+    Ok(kernings)
+}
+
+// *** Writing ***
+
+fn write_block<W: Write>(writer: &mut W, block_type: u8, block: &[u8]) -> io::Result<()>
+{
+    writer.write_all(&[block_type])?;
+    writer.write_all(&(block.len() as u32).to_le_bytes())?;
+    writer.write_all(block)
+}
+
+fn info_block(font_info: &FontInfo) -> io::Result<Vec<u8>>
+{
+    let mut block = Vec::new();
+
+    let mut bit_field = 0;
+    if font_info.smooth == Some(true)
+    {
+        bit_field |= SMOOTH_BIT;
+    }
+    if font_info.unicode == Some(true)
+    {
+        bit_field |= UNICODE_BIT;
+    }
+    if font_info.italic == Some(true)
+    {
+        bit_field |= ITALIC_BIT;
+    }
+    if font_info.bold == Some(true)
+    {
+        bit_field |= BOLD_BIT;
+    }
+
+    block.extend(to_i16("size", font_info.size)?.to_le_bytes());
+    block.push(bit_field);
+    block.push(charset_number(font_info.charset.as_deref())?);
+    block.extend(to_u16("stretchH", font_info.stretch_h)?.to_le_bytes());
+    block.push(to_u8("aa", font_info.aa)?);
+
+    for x in font_info.padding.unwrap_or([0; 4])
+    {
+        block.push(to_u8("padding", Some(x))?);
+    }
+
+    for x in font_info.spacing.unwrap_or([0; 2])
+    {
+        block.push(to_u8("spacing", Some(x))?);
+    }
+
+    block.push(to_u8("outline", font_info.outline)?);
+    push_string(&mut block, font_info.face.as_deref().unwrap_or(""))?;
+
+    Ok(block)
+}
+
+fn common_block(common_info: &CommonInfo) -> io::Result<Vec<u8>>
+{
+    let mut block = Vec::new();
+
+    block.extend(to_u16("lineHeight", common_info.line_height)?.to_le_bytes());
+    block.extend(to_u16("base", common_info.base)?.to_le_bytes());
+    block.extend(to_u16("scaleW", common_info.scale_w)?.to_le_bytes());
+    block.extend(to_u16("scaleH", common_info.scale_h)?.to_le_bytes());
+    block.extend(to_u16("pages", common_info.pages)?.to_le_bytes());
+    block.push(if common_info.is_packed() { PACKED_BIT } else { 0 });
+    block.push(to_u8("alphaChnl", common_info.alpha_chnl)?);
+    block.push(to_u8("redChnl", common_info.red_chnl)?);
+    block.push(to_u8("greenChnl", common_info.green_chnl)?);
+    block.push(to_u8("blueChnl", common_info.blue_chnl)?);
+
+    Ok(block)
+}
+
+fn pages_block(pages: &[PageInfo]) -> io::Result<Vec<u8>>
+{
+    let mut sorted_pages = pages.iter().collect::<Vec<&PageInfo>>();
+    sorted_pages.sort_by_key(|x| x.id);
+
+    let mut block = Vec::new();
+
+    for (index, page) in sorted_pages.iter().enumerate()
+    {
+        // The binary format does not store page ids- they are the position of the page in the block
+        if page.id != index as i32
+        {
+            return Err(invalid_input(format!("Page ids must start at 0 and be consecutive, found page id {}", page.id)));
+        }
+
+        // The format requires all page names to be of the same length
+        if page.file.len() != sorted_pages[0].file.len()
+        {
+            return Err(invalid_input(format!("Page file {} is not the same length as the other page files", page.file)));
+        }
+
+        push_string(&mut block, &page.file)?;
+    }
+
+    Ok(block)
+}
+
+fn chars_block(characters: &[CharacterInfo]) -> io::Result<Vec<u8>>
+{
+    let mut block = Vec::new();
+
+    for char_info in characters
+    {
+        block.extend(to_u32("id", char_info.id)?.to_le_bytes());
+        block.extend(to_u16("x", char_info.x)?.to_le_bytes());
+        block.extend(to_u16("y", char_info.y)?.to_le_bytes());
+        block.extend(to_u16("width", char_info.width)?.to_le_bytes());
+        block.extend(to_u16("height", char_info.height)?.to_le_bytes());
+        block.extend(to_i16("xoffset", char_info.x_offset)?.to_le_bytes());
+        block.extend(to_i16("yoffset", char_info.y_offset)?.to_le_bytes());
+        block.extend(to_i16("xadvance", char_info.x_advance)?.to_le_bytes());
+        block.push(to_u8("page", char_info.page)?);
+        block.push(to_u8("chnl", char_info.chnl)?);
+    }
+
+    Ok(block)
+}
+
+fn kerning_block(kernings: &[KerningPair]) -> io::Result<Vec<u8>>
+{
+    let mut block = Vec::new();
+
+    for kerning in kernings
+    {
+        block.extend(to_u32("first", Some(kerning.first))?.to_le_bytes());
+        block.extend(to_u32("second", Some(kerning.second))?.to_le_bytes());
+        block.extend(to_i16("amount", Some(kerning.amount))?.to_le_bytes());
+    }
+
+    Ok(block)
+}
+
+fn push_string(block: &mut Vec<u8>, string: &str) -> io::Result<()>
+{
+    // An embedded null would end the string early when it is read back
+    if string.contains('\0')
+    {
+        return Err(invalid_input(format!("String {:?} contains a null character", string)));
+    }
+
+    block.extend(string.as_bytes());
+    block.push(0);
+    Ok(())
+}
+
+// The binary format uses smaller integer types than the text format, so values have to be checked
+// to ensure that they fit
+
+/// Finds the number the binary format uses for the named character set. Fonts without a charset
+/// are written as ANSI, the charset used by the generator when none is chosen
+fn charset_number(charset: Option<&str>) -> io::Result<u8>
+{
+    match charset
+    {
+        None | Some("") => Ok(0),
+        Some(name) =>
+            {
+                match CHARSETS.iter().find(|x| x.1.eq_ignore_ascii_case(name))
+                {
+                    Some(x) => Ok(x.0),
+                    // Charsets unknown to the text format are written by their number
+                    None => name.parse().map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("Unknown charset {}", name)))
+                }
+            }
+    }
+}
+
+fn to_u8(identifier: &str, value: Option<i32>) -> io::Result<u8>
+{
+    u8::try_from(value.unwrap_or(0)).map_err(|_| out_of_range(identifier, value))
+}
+
+fn to_u16(identifier: &str, value: Option<i32>) -> io::Result<u16>
+{
+    u16::try_from(value.unwrap_or(0)).map_err(|_| out_of_range(identifier, value))
+}
+
+fn to_i16(identifier: &str, value: Option<i32>) -> io::Result<i16>
+{
+    i16::try_from(value.unwrap_or(0)).map_err(|_| out_of_range(identifier, value))
+}
+
+fn to_u32(identifier: &str, value: Option<i32>) -> io::Result<u32>
+{
+    u32::try_from(value.unwrap_or(0)).map_err(|_| out_of_range(identifier, value))
+}
+
+fn out_of_range(identifier: &str, value: Option<i32>) -> io::Error
+{
+    invalid_input(format!("Value {:?} of {} does not fit in the binary format", value, identifier))
+}
+
+fn invalid_input(message: String) -> io::Error
+{
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, CharConflict, DuplicateChars, parse_binary, parse_bytes_with_options, parse_file, parse_str, ParseError, ParseOptions, RectEdges};
+
+    #[test]
+    fn check_binary_round_trip()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, atlas_dimensions).unwrap();
+
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+        let read_back = parse_binary(&written, atlas_dimensions).unwrap();
+
+        assert_eq!(font.characters, read_back.characters);
+        assert_eq!(font.common, read_back.common);
+
+        // The packed flag and channels are kept in the common block
+        let mut packed = font.clone();
+        packed.common.packed = Some(1);
+        packed.common.red_chnl = Some(4);
+        let mut packed_written = Vec::new();
+        packed.write_binary(&mut packed_written).unwrap();
+        assert_eq!(packed.common, parse_binary(&packed_written, atlas_dimensions).unwrap().common);
+        assert_eq!(font.pages, read_back.pages);
+        assert_eq!(font.kernings, read_back.kernings);
+        assert_eq!(font.info.face, read_back.info.face);
+        assert_eq!(font.info.size, read_back.info.size);
+        assert_eq!(font.info.padding, read_back.info.padding);
+        assert_eq!(font.info.spacing, read_back.info.spacing);
+        assert_eq!(font.info.smooth, read_back.info.smooth);
+        assert_eq!(font.info.aa, read_back.info.aa);
+        assert_eq!(font.info.unicode, read_back.info.unicode);
+        assert_eq!(font.stats, read_back.stats);
+
+        // Once written in the binary format, every value is present and so the font no longer changes
+        let mut written_again = Vec::new();
+        read_back.write_binary(&mut written_again).unwrap();
+        assert_eq!(written, written_again);
+    }
+
+    #[test]
+    fn check_binary_rect_edges()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, atlas_dimensions).unwrap();
+
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+
+        let half_open = parse_bytes_with_options(&written, atlas_dimensions, &ParseOptions::default()).unwrap();
+        let options = ParseOptions{ rect_edges: RectEdges::Inclusive, ..Default::default() };
+        let inclusive = parse_bytes_with_options(&written, atlas_dimensions, &options).unwrap();
+
+        assert_eq!(font.characters, half_open.characters);
+
+        // Characters without an area have the same coordinates either way
+        for (half_open, inclusive) in half_open.characters.iter().zip(&inclusive.characters).filter(|x| x.0.width != Some(0))
+        {
+            assert_eq!(half_open.texture_coordinates[1], inclusive.texture_coordinates[1]);
+            assert_ne!(half_open.texture_coordinates[3], inclusive.texture_coordinates[3]);
+        }
+
+        // The same as the text version of the font read with inclusive areas
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let text = parse_bytes_with_options(&std::fs::read(test_file).unwrap(), atlas_dimensions, &options).unwrap();
+        assert_eq!(text.characters, inclusive.characters);
+    }
+
+    #[test]
+    fn check_binary_duplicate_chars()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, atlas_dimensions).unwrap();
+
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+
+        let options = ParseOptions{ duplicate_chars: DuplicateChars::Merge, ..Default::default() };
+        let merged = parse_bytes_with_options(&written, atlas_dimensions, &options).unwrap();
+
+        // The second listing of | is merged into the first, which keeps its own area
+        assert_eq!(&font.characters[0..2], merged.characters.as_slice());
+        assert_eq!(vec![CharConflict{ id: 124, field: "x", kept: 0, discarded: 256 }, CharConflict{ id: 124, field: "y", kept: 0, discarded: 256 }], merged.char_conflicts);
+        assert_eq!(3, merged.stats.chars);
+    }
+
+    #[test]
+    fn check_binary_block_sizes()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+
+        assert_eq!(b"BMF\x03", &written[0..4]);
+
+        // Info block: 14 bytes of fields followed by the null terminated font name
+        assert_eq!(1, written[4]);
+        assert_eq!(14 + "Arial".len() as u32 + 1, u32::from_le_bytes([written[5], written[6], written[7], written[8]]));
+
+        let common_start = 9 + 14 + "Arial".len() + 1;
+        assert_eq!(2, written[common_start]);
+        assert_eq!(15, u32::from_le_bytes(written[common_start + 1..common_start + 5].try_into().unwrap()));
+
+        let pages_start = common_start + 5 + 15;
+        assert_eq!(3, written[pages_start]);
+        assert_eq!("arial.png".len() as u32 + 1, u32::from_le_bytes(written[pages_start + 1..pages_start + 5].try_into().unwrap()));
+
+        let chars_start = pages_start + 5 + "arial.png".len() + 1;
+        assert_eq!(4, written[chars_start]);
+        assert_eq!(3 * 20, u32::from_le_bytes(written[chars_start + 1..chars_start + 5].try_into().unwrap()));
+
+        let kerning_start = chars_start + 5 + 3 * 20;
+        assert_eq!(5, written[kerning_start]);
+        assert_eq!(2 * 10, u32::from_le_bytes(written[kerning_start + 1..kerning_start + 5].try_into().unwrap()));
+        assert_eq!(kerning_start + 5 + 2 * 10, written.len());
+    }
+
+    #[test]
+    fn check_binary_charset()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let contents = "info face=\"Arial\" charset=\"RUSSIAN\" unicode=0\nchar id=192 x=0 y=0 width=10 height=10\n";
+        let font = parse_str(contents, atlas_dimensions).unwrap();
+
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+        let read_back = parse_binary(&written, atlas_dimensions).unwrap();
+        assert_eq!(Some("RUSSIAN".to_string()), read_back.info.charset);
+        assert_eq!(Some(false), read_back.info.unicode);
+
+        // Unicode fonts have no charset
+        let font = parse_str("info face=\"Arial\" charset=\"\" unicode=1\n", atlas_dimensions).unwrap();
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+        let read_back = parse_binary(&written, atlas_dimensions).unwrap();
+        assert_eq!(Some(String::new()), read_back.info.charset);
+        assert_eq!(Some(true), read_back.info.unicode);
+    }
+
+    #[test]
+    fn check_invalid_binary()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+
+        assert!(matches!(parse_binary(b"BMF", atlas_dimensions), Err(ParseError::InvalidBinary(_))));
+        assert!(matches!(parse_binary(b"BMF\x02", atlas_dimensions), Err(ParseError::InvalidBinary(_))));
+        // Block claims to be larger than the remaining data
+        assert!(matches!(parse_binary(b"BMF\x03\x04\xFF\x00\x00\x00\x01", atlas_dimensions), Err(ParseError::InvalidBinary(_))));
+    }
+}
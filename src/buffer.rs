@@ -0,0 +1,50 @@
+use crate::{CharacterInfo, Uv};
+
+/// Flattens the texture coordinates of the characters into a single list, ready to be uploaded to
+/// a vertex buffer. Each character adds eight values: the u and v coordinates of its bottom left,
+/// top left, top right and bottom right corners, in that order (the same order as the texture
+/// coordinates of a character)
+///
+/// `glyphs` - the characters whose texture coordinates are added, in the order they are added
+pub fn uv_buffer(glyphs: &[&CharacterInfo]) -> Vec<f32>
+{
+    let mut buffer = Vec::with_capacity(glyphs.len() * 8);
+
+    for glyph in glyphs
+    {
+        for Uv(u, v) in glyph.texture_coordinates
+        {
+            buffer.push(u);
+            buffer.push(v);
+        }
+    }
+
+    buffer
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, parse_file, uv_buffer};
+
+    #[test]
+    fn check_uv_buffer()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        let buffer = uv_buffer(&[&font.characters[2], &font.characters[0]]);
+        assert_eq!(16, buffer.len());
+
+        // The third character starts at (256, 256) and is 22 by 72 pixels
+        let right = (256.0 + 22.0) / 512.0;
+        let bottom = 1.0 - (256.0 + 72.0) / 512.0;
+        assert_eq!(vec![0.5, bottom, 0.5, 0.5, right, 0.5, right, bottom], buffer[0..8]);
+
+        let first_glyph_uvs = font.characters[0].texture_coordinates.iter().flat_map(|x| [x.0, x.1]).collect::<Vec<f32>>();
+        assert_eq!(first_glyph_uvs, buffer[8..16]);
+
+        assert!(uv_buffer(&[]).is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+/// A texture coordinate of the texture atlas, as the u and v from 0 to 1 across the atlas. As in
+/// OpenGL, v increases upward, so the top of the atlas has a v of 1. Kept separate from pixel
+/// positions so that the two cannot be passed in place of each other
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Uv(pub f32, pub f32);
+
+/// A position in pixels, as the x and y, such as the top left corner of a character in the texture
+/// atlas. As in the font file, y increases downward
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PixelPos(pub i32, pub i32);
+
+impl From<(f32, f32)> for Uv
+{
+    fn from((u, v): (f32, f32)) -> Uv
+    {
+        Uv(u, v)
+    }
+}
+
+impl From<Uv> for (f32, f32)
+{
+    fn from(uv: Uv) -> (f32, f32)
+    {
+        (uv.0, uv.1)
+    }
+}
+
+impl From<(i32, i32)> for PixelPos
+{
+    fn from((x, y): (i32, i32)) -> PixelPos
+    {
+        PixelPos(x, y)
+    }
+}
+
+impl From<PixelPos> for (i32, i32)
+{
+    fn from(position: PixelPos) -> (i32, i32)
+    {
+        (position.0, position.1)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{PixelPos, Uv};
+
+    #[test]
+    fn check_tuple_conversions()
+    {
+        assert_eq!(Uv(0.25, 0.75), Uv::from((0.25, 0.75)));
+        assert_eq!((0.25, 0.75), <(f32, f32)>::from(Uv(0.25, 0.75)));
+
+        assert_eq!(PixelPos(3, 4), PixelPos::from((3, 4)));
+        let (x, y) = PixelPos(3, 4).into();
+        assert_eq!((3, 4), (x, y));
+    }
+}
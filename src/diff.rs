@@ -0,0 +1,121 @@
+use std::collections::BTreeSet;
+use crate::{CharacterInfo, Font};
+
+/// The differences between two fonts, such as before and after regenerating the texture atlas.
+/// Characters are matched by their id; characters without an id are not compared. All of the lists
+/// of ids are sorted
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FontDiff
+{
+    /// The ids of the characters that are only in the other font
+    pub added: Vec<i32>,
+    /// The ids of the characters that are only in this font
+    pub removed: Vec<i32>,
+    /// The ids of the characters whose area of the atlas changed, including the page and channel
+    pub moved: Vec<i32>,
+    /// The ids of the characters whose offsets or advance changed
+    pub changed_metrics: Vec<i32>,
+    /// If the info lines of the fonts are different
+    pub info_changed: bool,
+    /// If the common lines of the fonts are different
+    pub common_changed: bool,
+    /// If any kerning pair was added, removed or has a different amount
+    pub kernings_changed: bool,
+}
+
+impl FontDiff
+{
+    /// Determines if the two fonts had no differences
+    pub fn is_empty(&self) -> bool
+    {
+        *self == FontDiff::default()
+    }
+}
+
+impl Font
+{
+    /// Finds what changed between this font and the other font. This font is treated as the older
+    /// of the two, so characters only in the other font are added
+    ///
+    /// `other` - the font to compare against, such as the font of a regenerated atlas
+    pub fn diff(&self, other: &Font) -> FontDiff
+    {
+        let ids = |font: &Font| font.characters.iter().filter_map(|x| x.id).collect::<BTreeSet<i32>>();
+        let (own_ids, other_ids) = (ids(self), ids(other));
+
+        let mut diff = FontDiff
+        {
+            added: other_ids.difference(&own_ids).copied().collect(),
+            removed: own_ids.difference(&other_ids).copied().collect(),
+            info_changed: self.info != other.info,
+            common_changed: self.common != other.common,
+            kernings_changed: self.kernings != other.kernings,
+            ..FontDiff::default()
+        };
+
+        for &id in own_ids.intersection(&other_ids)
+        {
+            if let (Some(own_glyph), Some(other_glyph)) = (self.glyph(id), other.glyph(id))
+            {
+                if atlas_area(own_glyph) != atlas_area(other_glyph)
+                {
+                    diff.moved.push(id);
+                }
+
+                if metrics(own_glyph) != metrics(other_glyph)
+                {
+                    diff.changed_metrics.push(id);
+                }
+            }
+        }
+
+        diff
+    }
+}
+
+/// Finds the values that say where the character is in the texture atlas
+fn atlas_area(char_info: &CharacterInfo) -> [Option<i32>; 7]
+{
+    [char_info.x, char_info.y, char_info.width, char_info.height, char_info.page, char_info.chnl, char_info.rot]
+}
+
+/// Finds the values that say where the character is placed when rendering text
+fn metrics(char_info: &CharacterInfo) -> [Option<i32>; 3]
+{
+    [char_info.x_offset, char_info.y_offset, char_info.x_advance]
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{AtlasDimensions, parse_str};
+
+    #[test]
+    fn check_diff()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let before = "common lineHeight=82 base=53\n\
+                      char id=65 x=0 y=0 width=10 height=10 xadvance=11\n\
+                      char id=66 x=10 y=0 width=10 height=10 xadvance=11\n\
+                      char id=67 x=20 y=0 width=10 height=10 xadvance=11\n\
+                      kerning first=65 second=66 amount=-1\n";
+        let after = "common lineHeight=82 base=53\n\
+                     char id=65 x=0 y=0 width=10 height=10 xadvance=11\n\
+                     char id=66 x=30 y=0 width=10 height=10 xadvance=12\n\
+                     char id=68 x=20 y=0 width=10 height=10 xadvance=11\n\
+                     kerning first=65 second=66 amount=-1\n";
+
+        let before = parse_str(before, atlas_dimensions).unwrap();
+        let after = parse_str(after, atlas_dimensions).unwrap();
+
+        let diff = before.diff(&after);
+        assert_eq!(vec![68], diff.added);
+        assert_eq!(vec![67], diff.removed);
+        assert_eq!(vec![66], diff.moved);
+        assert_eq!(vec![66], diff.changed_metrics);
+        assert!(!diff.info_changed && !diff.common_changed && !diff.kernings_changed);
+        assert!(!diff.is_empty());
+
+        assert!(before.diff(&before).is_empty());
+    }
+}
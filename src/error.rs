@@ -0,0 +1,100 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use crate::AtlasDimensions;
+
+/// The reasons why a font file could not be parsed
+#[derive(Debug)]
+pub enum ParseError
+{
+    /// The font file could not be opened. The location is kept as the default io error does not
+    /// say which file could not be opened
+    FileOpen { file_location: String, error: io::Error },
+    /// A line of the font file could not be read. Line numbers start from 1
+    LineRead { line_number: usize, error: io::Error },
+    /// A parameter of a line is not of the form `variable=value`, or its value could not be read.
+    /// Line numbers start from 1
+    InvalidParameter { line_number: usize, parameter: String },
+    /// None of the lines in the file were recognized as part of the angel font format, which
+    /// usually means that the wrong file was passed in
+    NotABmFont,
+    /// The contents of a binary font file do not follow the binary version of the angel font format
+    InvalidBinary(String),
+    /// The contents of an XML font file are not well formed XML
+    InvalidXml(String),
+    /// The contents of a text font file could not be converted into text
+    InvalidEncoding(String),
+    /// The size of the texture atlas could be found from neither the page images nor the common line
+    UnknownAtlasDimensions,
+    /// A character is on a page that does not have a page line
+    MissingPage { char_id: Option<i32>, page: i32 },
+    /// The size of the texture atlas passed in does not have an area, and so texture coordinates
+    /// cannot be found with it
+    InvalidAtlas { width: i32, height: i32 },
+    /// The value of a parameter is a number too large or too small to be stored, as opposed to
+    /// being left out. Line numbers start from 1
+    ValueOverflow { field: String, line: usize },
+    /// The image of a page is a different size than the texture atlas the texture coordinates were
+    /// found with, and so the texture coordinates do not match the image
+    AtlasMismatch { page: i32, expected: AtlasDimensions, actual: AtlasDimensions },
+}
+
+impl Display for ParseError
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ParseError::FileOpen { file_location, error } => write!(f, "Unable to open file {}, with the error: {}", file_location, error),
+            ParseError::LineRead { line_number, error } => write!(f, "Unable to read line number {} with error: {}", line_number, error),
+            ParseError::InvalidParameter { line_number, parameter } => write!(f, "Unable to read parameter {} on line number {}", parameter, line_number),
+            ParseError::NotABmFont => write!(f, "No lines of the file were recognized as being part of the angel font format"),
+            ParseError::InvalidBinary(reason) => write!(f, "Invalid binary font: {}", reason),
+            ParseError::InvalidXml(reason) => write!(f, "Invalid XML font: {}", reason),
+            ParseError::InvalidEncoding(reason) => write!(f, "Invalid text encoding: {}", reason),
+            ParseError::UnknownAtlasDimensions => write!(f, "Unable to find the size of the texture atlas"),
+            ParseError::MissingPage { char_id: Some(char_id), page } => write!(f, "Character {} is on page {}, which does not exist", char_id, page),
+            ParseError::MissingPage { char_id: None, page } => write!(f, "A character without an id is on page {}, which does not exist", page),
+            ParseError::InvalidAtlas { width, height } => write!(f, "Invalid atlas size of {} by {}, both must be positive", width, height),
+            ParseError::ValueOverflow { field, line } => write!(f, "The value of {} on line number {} does not fit in a 32 bit integer", field, line),
+            ParseError::AtlasMismatch { page, expected, actual } =>
+                write!(f, "The image of page {} is {} by {}, but the atlas is {} by {}", page, actual.width, actual.height, expected.width, expected.height),
+        }
+    }
+}
+
+impl Error for ParseError
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)>
+    {
+        match self
+        {
+            ParseError::FileOpen { error, .. } | ParseError::LineRead { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A value of a character that is needed but was missing or invalid in the font file
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingField
+{
+    /// The id of the character, if it has one
+    pub id: Option<i32>,
+    /// The name of the missing parameter, as written in the font file
+    pub field: &'static str,
+}
+
+impl Display for MissingField
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        match self.id
+        {
+            Some(id) => write!(f, "glyph {} missing {}", id, self.field),
+            None => write!(f, "glyph without an id missing {}", self.field),
+        }
+    }
+}
+
+impl Error for MissingField {}
@@ -0,0 +1,156 @@
+use std::ffi::{c_char, CStr};
+use std::ptr;
+use crate::{parse_file, AtlasDimensions, CharacterInfo, Uv};
+
+/// The value used by CharacterInfoC for parameters that are missing or could not be read
+pub const ABSENT: i32 = i32::MIN;
+
+/// The information of a character in a form that can be used from C. Parameters that are missing
+/// or could not be read have the value ABSENT
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CharacterInfoC
+{
+    pub id: i32,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+    pub x_advance: i32,
+    pub page: i32,
+    pub chnl: i32,
+    pub rot: i32,
+    /// The texture coordinates as (u, v) pairs, in the same order as CharacterInfo
+    pub texture_coordinates: [[f32; 2]; 4],
+}
+
+impl From<&CharacterInfo> for CharacterInfoC
+{
+    fn from(char_info: &CharacterInfo) -> CharacterInfoC
+    {
+        CharacterInfoC
+        {
+            id: char_info.id.unwrap_or(ABSENT),
+            x: char_info.x.unwrap_or(ABSENT),
+            y: char_info.y.unwrap_or(ABSENT),
+            width: char_info.width.unwrap_or(ABSENT),
+            height: char_info.height.unwrap_or(ABSENT),
+            x_offset: char_info.x_offset.unwrap_or(ABSENT),
+            y_offset: char_info.y_offset.unwrap_or(ABSENT),
+            x_advance: char_info.x_advance.unwrap_or(ABSENT),
+            page: char_info.page.unwrap_or(ABSENT),
+            chnl: char_info.chnl.unwrap_or(ABSENT),
+            rot: char_info.rot.unwrap_or(ABSENT),
+            texture_coordinates: char_info.texture_coordinates.map(|Uv(u, v)| [u, v]),
+        }
+    }
+}
+
+impl From<CharacterInfo> for CharacterInfoC
+{
+    fn from(char_info: CharacterInfo) -> CharacterInfoC
+    {
+        CharacterInfoC::from(&char_info)
+    }
+}
+
+/// Reads the characters of the passed in font file, for use from C. The returned characters must
+/// be freed with angel_font_free_characters. If the file could not be read, null is returned
+///
+/// # Safety
+///
+/// `file_location` must be a valid null terminated string, and `character_count` must point to
+/// memory the number of returned characters can be written to
+///
+/// `file_location` - the location of the file in the angel file format, encoded as UTF-8
+/// `character_count` - where the number of returned characters is written to
+#[no_mangle]
+pub unsafe extern "C" fn angel_font_parse(file_location: *const c_char, atlas_width: i32, atlas_height: i32, character_count: *mut usize) -> *mut CharacterInfoC
+{
+    if file_location.is_null() || character_count.is_null()
+    {
+        return ptr::null_mut();
+    }
+
+    let file_location = match CStr::from_ptr(file_location).to_str()
+    {
+        Ok(i) => i,
+        Err(_) => return ptr::null_mut()
+    };
+
+    match parse_file(file_location, AtlasDimensions { width: atlas_width, height: atlas_height })
+    {
+        Ok(font) =>
+            {
+                let characters = font.characters.iter().map(CharacterInfoC::from).collect::<Box<[CharacterInfoC]>>();
+                *character_count = characters.len();
+                Box::into_raw(characters) as *mut CharacterInfoC
+            },
+        Err(_) => ptr::null_mut()
+    }
+}
+
+/// Frees the characters returned by angel_font_parse
+///
+/// # Safety
+///
+/// `characters` must have been returned by angel_font_parse along with `character_count`, and
+/// must not have been freed already. Null is ignored
+#[no_mangle]
+pub unsafe extern "C" fn angel_font_free_characters(characters: *mut CharacterInfoC, character_count: usize)
+{
+    if !characters.is_null()
+    {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(characters, character_count)));
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use std::ffi::CString;
+    use crate::{AtlasDimensions, extract_characters, parse_str};
+    use crate::ffi::{ABSENT, angel_font_free_characters, angel_font_parse, CharacterInfoC};
+
+    #[test]
+    fn check_character_conversion()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("missing_parameters.fnt");
+        let characters = extract_characters(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        let converted = CharacterInfoC::from(&characters[1]);
+        assert_eq!(32, converted.id);
+        assert_eq!(ABSENT, converted.x);
+        assert_eq!(ABSENT, converted.y);
+        assert_eq!(53, converted.y_offset);
+        assert_eq!(ABSENT, converted.rot);
+        assert_eq!(CharacterInfoC::from(characters[0].clone()).texture_coordinates[1], [characters[0].texture_coordinates[1].0, characters[0].texture_coordinates[1].1]);
+
+        let font = parse_str("char id=65 x=0 y=0 width=10 height=12 rot=90\n", AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(90, CharacterInfoC::from(&font.characters[0]).rot);
+    }
+
+    #[test]
+    fn check_c_parse()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let file_location = CString::new(test_file.to_str().unwrap()).unwrap();
+        let mut character_count = 0;
+
+        unsafe
+        {
+            let characters = angel_font_parse(file_location.as_ptr(), 512, 512, &mut character_count);
+            assert!(!characters.is_null());
+            assert_eq!(3, character_count);
+            assert_eq!(124, (*characters).id);
+            assert_eq!(256, (*characters.add(2)).x);
+            angel_font_free_characters(characters, character_count);
+
+            let missing_file = CString::new("does_not_exist.fnt").unwrap();
+            assert!(angel_font_parse(missing_file.as_ptr(), 512, 512, &mut character_count).is_null());
+        }
+    }
+}
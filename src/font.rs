@@ -0,0 +1,1421 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use crate::{calculate_char_texture_coords, AtlasDimensions, CharacterInfo, extract_numeric_value, extract_parameters, unescape, RectEdges, Uv};
+
+/// Stores all of the information that was read from a font file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Font
+{
+    pub info: FontInfo,
+    pub common: CommonInfo,
+    /// The type and range of the distance field, for signed distance field fonts that have a
+    /// distanceField line, such as those of msdf-bmfont. Other fonts do not have one
+    pub distance_field: Option<DistanceFieldInfo>,
+    pub pages: Vec<PageInfo>,
+    /// The characters in the same order as they are listed in the font file. Characters are not
+    /// sorted, and are only deduplicated if the parse options merge duplicates, so the index of a
+    /// character is the same every time the file is read
+    pub characters: Vec<CharacterInfo>,
+    /// The values of duplicated characters that were discarded when merging them, because the
+    /// first listing of the character has a different value
+    pub char_conflicts: Vec<CharConflict>,
+    /// The kerning amount of each (first, second) character pair. If the same pair is listed more
+    /// than once, the last listed amount is used
+    pub kernings: HashMap<(i32, i32), i32>,
+    /// The kerning pairs that were replaced by a later listing of the same pair with a different amount
+    pub kerning_conflicts: Vec<KerningPair>,
+    /// The lines of the font file that are not part of the angel font format, such as the lines
+    /// of extensions added by other generators. Blank lines and comments are not kept
+    pub unknown_lines: Vec<String>,
+    /// How much of the font file was understood when it was read
+    pub stats: ParseStats,
+    /// The ids of the characters, used to quickly check if the font has a character
+    glyph_ids: GlyphIds,
+    /// The size of the texture atlas and the sides of the character areas the texture coordinates
+    /// were found with, used to find them again for characters that are changed
+    atlas_dimensions: AtlasDimensions,
+    rect_edges: RectEdges,
+    /// How many times larger than their areas of the atlas the characters are drawn
+    draw_scale: f32,
+}
+
+impl Font
+{
+    /// Creates a font without any information, whose texture coordinates are found with the given
+    /// atlas size and sides of the character areas
+    pub(crate) fn new(atlas_dimensions: AtlasDimensions, rect_edges: RectEdges) -> Font
+    {
+        Font
+        {
+            info: FontInfo::new(),
+            common: CommonInfo::new(),
+            distance_field: None,
+            pages: Vec::new(),
+            characters: Vec::new(),
+            kernings: HashMap::new(),
+            char_conflicts: Vec::new(),
+            kerning_conflicts: Vec::new(),
+            unknown_lines: Vec::new(),
+            stats: ParseStats::default(),
+            glyph_ids: GlyphIds::default(),
+            atlas_dimensions,
+            rect_edges,
+            draw_scale: 1.0,
+        }
+    }
+
+    /// Finds the size of the texture atlas the texture coordinates of the characters were found
+    /// with, which is the size the font was read with unless they were recalculated
+    pub fn atlas_dimensions(&self) -> AtlasDimensions
+    {
+        self.atlas_dimensions
+    }
+
+    /// Changes the character with the given id, such as to tune its offsets or advance while
+    /// developing without exporting the font again. If the position, size or rotation of the area of
+    /// the character in the atlas changed, its texture coordinates are found again with the atlas
+    /// size the font was read with. If the id changed, the character is found by its new id
+    /// afterwards. Returns if the font has a character with the given id
+    ///
+    /// `id` - the id of the character to change
+    /// `patch` - the changes to make to the character
+    pub fn patch_glyph(&mut self, id: i32, patch: impl FnOnce(&mut CharacterInfo)) -> bool
+    {
+        let index = match self.glyph_index(id)
+        {
+            Some(index) => index,
+            None => return false
+        };
+
+        let char_info = &mut self.characters[index];
+        let area = |x: &CharacterInfo| (x.x, x.y, x.width, x.height, x.is_rotated());
+        let previous_area = area(char_info);
+        patch(char_info);
+
+        if area(char_info) != previous_area
+        {
+            // Characters that no longer have a complete area do not have texture coordinates
+            char_info.texture_coordinates = [Uv(0.0, 0.0); 4];
+            calculate_char_texture_coords(char_info, self.atlas_dimensions, self.rect_edges);
+        }
+
+        if char_info.id != Some(id)
+        {
+            self.rebuild_glyph_ids();
+        }
+
+        true
+    }
+
+    /// Updates the ids used by contains to match the characters of the font. This is done when the
+    /// font is read, and only has to be done again if the characters are changed afterwards
+    pub fn rebuild_glyph_ids(&mut self)
+    {
+        let mut glyph_ids = GlyphIds::default();
+
+        for (index, char_info) in self.characters.iter().enumerate()
+        {
+            if let Some(id) = char_info.id
+            {
+                glyph_ids.insert(id, index);
+            }
+        }
+
+        self.glyph_ids = glyph_ids;
+    }
+
+    /// Finds the texture coordinates of every character again, for when the size of the texture
+    /// atlas the font was read with was not the actual size. The character areas are treated as
+    /// half-open, the same as the default parse options
+    ///
+    /// `atlas_dimensions` - the actual size of the texture atlas
+    pub fn recalculate_texture_coords(&mut self, atlas_dimensions: AtlasDimensions)
+    {
+        for char_info in &mut self.characters
+        {
+            char_info.texture_coordinates = [Uv(0.0, 0.0); 4];
+            calculate_char_texture_coords(char_info, atlas_dimensions, RectEdges::HalfOpen);
+        }
+
+        self.atlas_dimensions = atlas_dimensions;
+        self.rect_edges = RectEdges::HalfOpen;
+    }
+
+    /// Removes every character whose id is not one of the given ids, along with the kerning pairs
+    /// of the removed characters. Loading only the characters that are used saves memory
+    ///
+    /// `ids` - the ids of the characters to keep
+    pub fn retain_ids(&mut self, ids: &HashSet<i32>)
+    {
+        let is_kept = |id: Option<i32>| id.is_some_and(|x| ids.contains(&x));
+
+        self.characters.retain(|x| is_kept(x.id));
+        self.char_conflicts.retain(|x| is_kept(Some(x.id)));
+        self.kernings.retain(|&(first, second), _| is_kept(Some(first)) && is_kept(Some(second)));
+        self.kerning_conflicts.retain(|x| is_kept(Some(x.first)) && is_kept(Some(x.second)));
+        self.rebuild_glyph_ids();
+    }
+
+    /// Determines if the font has a character with the given id, without searching through all of
+    /// the characters. See rebuild_glyph_ids if the characters were changed after reading the font
+    pub fn contains(&self, id: i32) -> bool
+    {
+        self.glyph_ids.contains(id)
+    }
+
+    /// Finds which of the required characters the font does not have, such as to check that a font
+    /// can draw all of the text of a translated user interface. The missing characters are in the
+    /// order they are first listed in, and are only listed once
+    ///
+    /// `required` - the characters the font should have, such as the characters of every string
+    pub fn missing_from(&self, required: &[char]) -> Vec<char>
+    {
+        let mut listed = HashSet::new();
+        required.iter().copied().filter(|x| !self.contains(*x as i32) && listed.insert(*x)).collect()
+    }
+
+    /// Fills in the missing values of the character at the given index with those of a later listing
+    /// of the same character. Values that both listings have but that are different are kept as
+    /// they were, and the discarded value is added to the char conflicts
+    ///
+    /// `index` - the index of the first listing of the character
+    /// `later` - the later listing of the character
+    pub(crate) fn merge_character(&mut self, index: usize, later: &CharacterInfo)
+    {
+        let earlier = &mut self.characters[index];
+        let id = earlier.id.unwrap_or(0);
+
+        let values = [("x", &mut earlier.x, later.x), ("y", &mut earlier.y, later.y), ("width", &mut earlier.width, later.width),
+                      ("height", &mut earlier.height, later.height), ("xoffset", &mut earlier.x_offset, later.x_offset),
+                      ("yoffset", &mut earlier.y_offset, later.y_offset), ("xadvance", &mut earlier.x_advance, later.x_advance),
+                      ("page", &mut earlier.page, later.page), ("chnl", &mut earlier.chnl, later.chnl), ("rot", &mut earlier.rot, later.rot)];
+
+        for (field, value, later_value) in values
+        {
+            match (*value, later_value)
+            {
+                (None, _) => *value = later_value,
+                (Some(kept), Some(discarded)) if kept != discarded => self.char_conflicts.push(CharConflict { id, field, kept, discarded }),
+                _ => {}
+            }
+        }
+    }
+
+    /// Adds the kerning pair to the font, replacing any previous amount of the same pair
+    pub(crate) fn add_kerning(&mut self, kerning: KerningPair)
+    {
+        if let Some(previous_amount) = self.kernings.insert((kerning.first, kerning.second), kerning.amount)
+        {
+            // Listing the same pair twice is harmless as long as the amounts agree
+            if previous_amount != kerning.amount
+            {
+                self.kerning_conflicts.push(KerningPair { amount: previous_amount, ..kerning });
+            }
+        }
+    }
+
+    /// Finds the amount the horizontal position should be adjusted by when the second character
+    /// immediately follows the first character. Pairs without kerning information are not adjusted
+    ///
+    /// `first` - the id of the character that comes first
+    /// `second` - the id of the character that immediately follows the first character
+    pub fn kerning_amount(&self, first: i32, second: i32) -> i32
+    {
+        self.kernings.get(&(first, second)).copied().unwrap_or(0)
+    }
+
+    /// Lists the kerning pairs ordered by their character ids, so that the pairs are always in the
+    /// same order when written
+    pub(crate) fn sorted_kernings(&self) -> Vec<KerningPair>
+    {
+        let mut kernings = self.kernings.iter().map(|(&(first, second), &amount)| KerningPair { first, second, amount }).collect::<Vec<KerningPair>>();
+        kernings.sort_by_key(|x| (x.first, x.second));
+        kernings
+    }
+
+    /// Finds the character with the given id. If the font has more than one character with the id,
+    /// the first one is used
+    pub fn glyph(&self, id: i32) -> Option<&CharacterInfo>
+    {
+        self.glyph_index(id).map(|index| &self.characters[index])
+    }
+
+    /// Finds the index of the character with the given id in the characters of the font
+    fn glyph_index(&self, id: i32) -> Option<usize>
+    {
+        // The characters are public and so may have changed since the ids were found; the indexed
+        // character is only used if it still has the id
+        match self.glyph_ids.index_of(id)
+        {
+            Some(index) if self.characters.get(index).is_some_and(|x| x.id == Some(id)) => Some(index),
+            _ => self.characters.iter().position(|x| x.id == Some(id))
+        }
+    }
+
+    /// Finds how far the horizontal position moves after drawing the character with the given id,
+    /// without having to look at the rest of the character information
+    pub fn advance_of(&self, id: i32) -> Option<i32>
+    {
+        self.glyph(id).and_then(|x| x.x_advance)
+    }
+
+    /// Finds the advance of the character when the font is drawn at the given scale, where a scale
+    /// of 1 is one em (the size of the font in the info line). This allows the same texture atlas
+    /// to be drawn at any size
+    ///
+    /// `id` - the id of the character
+    /// `scale` - the size, such as in pixels, one em is drawn at
+    pub fn scaled_advance(&self, id: i32, scale: f32) -> Option<f32>
+    {
+        Some(self.advance_of(id)? as f32 * self.em_scale(scale)?)
+    }
+
+    /// Finds the offset of the character from the pen position when the font is drawn at the given
+    /// scale, as the horizontal and vertical offset. See scaled_advance for the meaning of the scale
+    ///
+    /// `id` - the id of the character
+    /// `scale` - the size, such as in pixels, one em is drawn at
+    pub fn scaled_offset(&self, id: i32, scale: f32) -> Option<(f32, f32)>
+    {
+        let glyph = self.glyph(id)?;
+        let em_scale = self.em_scale(scale)?;
+
+        Some((glyph.x_offset? as f32 * em_scale, glyph.y_offset? as f32 * em_scale))
+    }
+
+    /// Finds the size of the character when the font is drawn at the given scale, as the width and
+    /// height. See scaled_advance for the meaning of the scale
+    ///
+    /// `id` - the id of the character
+    /// `scale` - the size, such as in pixels, one em is drawn at
+    pub fn scaled_size(&self, id: i32, scale: f32) -> Option<(f32, f32)>
+    {
+        let (width, height) = self.draw_size(self.glyph(id)?)?;
+        let em_scale = self.em_scale(scale)?;
+
+        Some((width * em_scale, height * em_scale))
+    }
+
+    /// Finds how many times larger than their areas of the atlas the characters are drawn. This is
+    /// 1 unless the font was scaled
+    pub fn draw_scale(&self) -> f32
+    {
+        self.draw_scale
+    }
+
+    /// Finds the size the character is drawn at, as the width and height in pixels. This is the
+    /// size of its area of the atlas multiplied by the draw scale of the font
+    ///
+    /// `char_info` - the character, usually of this font, to find the size of
+    pub fn draw_size(&self, char_info: &CharacterInfo) -> Option<(f32, f32)>
+    {
+        let (width, height) = char_info.size()?;
+        Some((width as f32 * self.draw_scale, height as f32 * self.draw_scale))
+    }
+
+    /// Creates a copy of the font with every metric used to lay out and draw text multiplied by
+    /// the given factor, so that the same atlas can be drawn at a different size, such as a font
+    /// generated at 16 pixels drawn at 32 pixels with a factor of 2. The size, padding and spacing
+    /// of the font, the line height, base and kerning amounts, and the offsets and advance of each
+    /// character are scaled and rounded to the nearest pixel. The areas of the characters in the
+    /// atlas and their texture coordinates are unchanged; the size the characters are drawn at is
+    /// instead found with draw_size. As the draw scale is not part of the font file, a written
+    /// scaled font has the scaled metrics but characters drawn at the size of their areas. How good
+    /// the scaled text looks depends on the filtering used when sampling the atlas; scaling up a
+    /// bitmap font blurs or pixelates it unless it is a distance field font
+    ///
+    /// `factor` - what each metric is multiplied by
+    pub fn scaled(&self, factor: f32) -> Font
+    {
+        let scale = |value: Option<i32>| value.map(|x| (x as f32 * factor).round() as i32);
+        let scale_value = |value: i32| (value as f32 * factor).round() as i32;
+        let mut font = self.clone();
+
+        font.draw_scale *= factor;
+        font.info.size = scale(font.info.size);
+        font.info.padding = font.info.padding.map(|x| x.map(scale_value));
+        font.info.spacing = font.info.spacing.map(|x| x.map(scale_value));
+        font.common.line_height = scale(font.common.line_height);
+        font.common.base = scale(font.common.base);
+
+        for char_info in &mut font.characters
+        {
+            char_info.x_offset = scale(char_info.x_offset);
+            char_info.y_offset = scale(char_info.y_offset);
+            char_info.x_advance = scale(char_info.x_advance);
+
+            // Fractional values are scaled before rounding, so they stay as exact as they were read
+            if let Some(fractional) = char_info.fractional.map(|x| x.scaled(factor))
+            {
+                char_info.fractional = Some(fractional);
+                char_info.x_offset = fractional.x_offset.map(|x| x.round() as i32).or(char_info.x_offset);
+                char_info.y_offset = fractional.y_offset.map(|x| x.round() as i32).or(char_info.y_offset);
+                char_info.x_advance = fractional.x_advance.map(|x| x.round() as i32).or(char_info.x_advance);
+            }
+        }
+
+        for amount in font.kernings.values_mut()
+        {
+            *amount = scale_value(*amount);
+        }
+
+        for kerning in &mut font.kerning_conflicts
+        {
+            kerning.amount = scale_value(kerning.amount);
+        }
+
+        font
+    }
+
+    /// Finds what the pixel values of the font are multiplied by to draw the font at the given
+    /// scale. Fonts without a size cannot be scaled
+    fn em_scale(&self, scale: f32) -> Option<f32>
+    {
+        // The sign of the size does not change the number of pixels
+        match self.info.size_abs()?
+        {
+            0 => None,
+            size => Some(scale / size as f32)
+        }
+    }
+
+    /// Converts the id of the character into the character it represents. The ids of fonts that are
+    /// not unicode are indices into the charset of the font, so no conversion is made for them
+    ///
+    /// `glyph` - the character, usually of this font, to convert
+    pub fn char_of(&self, glyph: &CharacterInfo) -> Option<char>
+    {
+        if self.info.unicode == Some(false)
+        {
+            return None;
+        }
+
+        glyph.as_char()
+    }
+
+    /// Finds the number of pages, which is the number of page lines of the font
+    pub fn page_count(&self) -> usize
+    {
+        self.pages.len()
+    }
+
+    /// Groups the characters by the page of the texture atlas they are on, so that all of the
+    /// characters of one page can be drawn before moving to the next page. Characters without a
+    /// page are not part of any group
+    pub fn glyphs_by_page(&self) -> HashMap<i32, Vec<&CharacterInfo>>
+    {
+        let mut pages: HashMap<i32, Vec<&CharacterInfo>> = HashMap::new();
+
+        for char_info in &self.characters
+        {
+            if let Some(page) = char_info.page
+            {
+                pages.entry(page).or_default().push(char_info);
+            }
+        }
+
+        pages
+    }
+
+    /// Lists the characters in the order they are stored in the texture atlas: by page, then from
+    /// top to bottom, then from left to right. Uploading characters in this order reads the atlas
+    /// sequentially. Characters without a position are listed after the others of their page, and
+    /// characters without a page after all of the pages
+    pub fn glyphs_by_atlas_position(&self) -> Vec<&CharacterInfo>
+    {
+        let mut glyphs = self.characters.iter().collect::<Vec<&CharacterInfo>>();
+
+        // None sorts before every value, so positions are wrapped to move unknown positions last
+        glyphs.sort_by_key(|x| (x.page.is_none(), x.page, x.y.is_none(), x.y, x.x.is_none(), x.x));
+        glyphs
+    }
+
+    /// Finds the smallest and largest ids of the characters, or None if no character has an id
+    pub fn id_range(&self) -> Option<(i32, i32)>
+    {
+        let ids = self.characters.iter().filter_map(|x| x.id);
+        Some((ids.clone().min()?, ids.max()?))
+    }
+
+    /// Finds the largest width and the largest height of the characters, in pixels, which is the
+    /// smallest cell that fits every character of the font. The width and height can come from
+    /// different characters. A font without any drawn characters has a size of (0, 0)
+    pub fn max_glyph_size(&self) -> (i32, i32)
+    {
+        let sizes = self.drawn_glyph_sizes();
+        (sizes.clone().map(|x| x.0).max().unwrap_or(0), sizes.map(|x| x.1).max().unwrap_or(0))
+    }
+
+    /// Finds the smallest width and the smallest height of the characters, in pixels. Characters
+    /// without an area, such as the space, are not drawn and so are left out. The width and height
+    /// can come from different characters. A font without any drawn characters has a size of (0, 0)
+    pub fn min_glyph_size(&self) -> (i32, i32)
+    {
+        let sizes = self.drawn_glyph_sizes();
+        (sizes.clone().map(|x| x.0).min().unwrap_or(0), sizes.map(|x| x.1).min().unwrap_or(0))
+    }
+
+    /// Finds the sizes of the characters that have an area
+    fn drawn_glyph_sizes(&self) -> impl Iterator<Item = (i32, i32)> + Clone + '_
+    {
+        self.characters.iter().filter_map(|x| x.size()).filter(|&(width, height)| width > 0 && height > 0)
+    }
+
+    /// Finds all of the characters whose id is within the given range. Characters without an id
+    /// are never part of the result
+    ///
+    /// `start` - the first id (inclusive) of the range
+    /// `end` - the last id (inclusive) of the range
+    pub fn glyphs_in_range(&self, start: u32, end: u32) -> impl Iterator<Item = &CharacterInfo>
+    {
+        self.characters.iter().filter(move |x|
+            {
+                // Negative ids cannot be a Unicode code point, so they are never in range
+                match x.id.and_then(|id| u32::try_from(id).ok())
+                {
+                    Some(id) => start <= id && id <= end,
+                    None => false
+                }
+            })
+    }
+}
+
+/// The ids of the characters of a font, along with the index of the first character with each id.
+/// As most fonts use ASCII, whether those ids are present is also stored as bits
+#[derive(Debug, Clone, Default, PartialEq)]
+struct GlyphIds
+{
+    ascii: u128,
+    indices: HashMap<i32, usize>,
+}
+
+impl GlyphIds
+{
+    fn insert(&mut self, id: i32, index: usize)
+    {
+        if (0..128).contains(&id)
+        {
+            self.ascii |= 1 << id;
+        }
+
+        self.indices.entry(id).or_insert(index);
+    }
+
+    fn contains(&self, id: i32) -> bool
+    {
+        match id
+        {
+            0..=127 => self.ascii & (1 << id) != 0,
+            _ => self.indices.contains_key(&id)
+        }
+    }
+
+    fn index_of(&self, id: i32) -> Option<usize>
+    {
+        self.indices.get(&id).copied()
+    }
+}
+
+/// Counts the lines of a font file by how they were read, to check that the file was fully
+/// understood. For binary font files, each block is counted in place of a line
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct ParseStats
+{
+    /// The number of char lines read, including those with invalid parameters
+    pub chars: usize,
+    /// The number of kerning lines that resulted in a kerning pair
+    pub kernings: usize,
+    /// The number of lines that are not part of the angel font format. Blank lines and comments
+    /// are not counted
+    pub skipped: usize,
+    /// The number of lines with at least one parameter that could not be read
+    pub errored: usize,
+    /// If the file has an info line
+    pub info_found: bool,
+    /// If the file has a common line
+    pub common_found: bool,
+}
+
+/// Stores the information of how the font was generated, read from the info line
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontInfo
+{
+    pub face: Option<String>,
+    /// The size of the font in pixels, as it is written in the file. A negative size means the
+    /// generator matched the height of the characters to the size rather than the height of the
+    /// character cells; see size_abs for the size regardless of which was used
+    pub size: Option<i32>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    /// The name of the character set the ids refer to when the font is not unicode, such as ANSI.
+    /// Unicode fonts usually have an empty charset
+    pub charset: Option<String>,
+    /// If the ids of the characters are Unicode code points. Otherwise the ids are indices into
+    /// the charset
+    pub unicode: Option<bool>,
+    pub stretch_h: Option<i32>,
+    /// If smoothing was turned on when the characters were rendered. Smoothed characters are
+    /// usually sampled with linear filtering, others with nearest filtering
+    pub smooth: Option<bool>,
+    /// The supersampling level the characters were rendered with. 1 means that the characters were
+    /// not supersampled, and higher values that they were rendered that many times larger and then
+    /// scaled down. Generators that only record whether anti-aliasing was used write 0 or 1
+    pub aa: Option<i32>,
+    /// The padding of each character in the order of up, right, down, left
+    pub padding: Option<[i32; 4]>,
+    /// The spacing of each character in the order of horizontal, vertical
+    pub spacing: Option<[i32; 2]>,
+    pub outline: Option<i32>,
+    /// The range in pixels of the distances stored in the texture of a signed distance field
+    /// font, needed by the shaders that render it. Fonts that are not distance fields do not have one
+    pub distance_range: Option<i32>,
+}
+
+impl FontInfo
+{
+    /// Creates font information with no usable information
+    pub(crate) fn new() -> FontInfo
+    {
+        FontInfo
+        {
+            face: None,
+            size: None,
+            bold: None,
+            italic: None,
+            charset: None,
+            unicode: None,
+            stretch_h: None,
+            smooth: None,
+            aa: None,
+            padding: None,
+            spacing: None,
+            outline: None,
+            distance_range: None,
+        }
+    }
+
+    /// Finds the size of the font in pixels without its sign, which only records how the size was
+    /// matched when the font was generated
+    pub fn size_abs(&self) -> Option<i32>
+    {
+        self.size.map(i32::abs)
+    }
+
+    /// Determines if the size of the font is the height of the characters, which the generator
+    /// records with a negative size. Otherwise the size is the height of the character cells
+    pub fn matches_char_height(&self) -> Option<bool>
+    {
+        self.size.map(|x| x < 0)
+    }
+
+    /// Finds how many times larger the characters were rendered before being scaled down to their
+    /// size in the atlas. Fonts that were not supersampled, including those without an aa
+    /// parameter, have a factor of 1
+    pub fn supersample(&self) -> i32
+    {
+        self.aa.unwrap_or(1).max(1)
+    }
+
+    /// Parses the given info line. Parameters that are missing or have an invalid value are not set
+    ///
+    /// `invalid_parameters` - where the parameters that could not be read are added to
+    pub(crate) fn from_line(line: &str, invalid_parameters: &mut Vec<String>) -> FontInfo
+    {
+        let mut font_info = FontInfo::new();
+
+        for (identifier, value) in extract_parameters(line, invalid_parameters)
+        {
+            let is_valid = match identifier
+            {
+                // Face names and charsets can have quotes and backslashes in them, which are escaped
+                // with a backslash
+                "face" => set_parsed(&mut font_info.face, Some(unescape(value))),
+                "size" => set_parsed(&mut font_info.size, value.parse().ok()),
+                "bold" => set_parsed(&mut font_info.bold, parse_flag(value)),
+                "italic" => set_parsed(&mut font_info.italic, parse_flag(value)),
+                "charset" => set_parsed(&mut font_info.charset, Some(unescape(value))),
+                "unicode" => set_parsed(&mut font_info.unicode, parse_flag(value)),
+                "stretchH" => set_parsed(&mut font_info.stretch_h, value.parse().ok()),
+                "smooth" => set_parsed(&mut font_info.smooth, parse_flag(value)),
+                "aa" => set_parsed(&mut font_info.aa, value.parse().ok().filter(|x: &i32| *x >= 0)),
+                "padding" => set_parsed(&mut font_info.padding, parse_list(value)),
+                "spacing" => set_parsed(&mut font_info.spacing, parse_list(value)),
+                "outline" => set_parsed(&mut font_info.outline, value.parse().ok()),
+                "distanceRange" => set_parsed(&mut font_info.distance_range, value.parse().ok()),
+                _ => true
+            };
+
+            if !is_valid
+            {
+                invalid_parameters.push(format!("{}={}", identifier, value));
+            }
+        }
+
+        font_info
+    }
+
+    /// Parses the given distanceField line, which generators of signed distance field fonts add
+    /// after the info line
+    ///
+    /// `invalid_parameters` - where the parameters that could not be read are added to
+    pub(crate) fn read_distance_field_line(&mut self, line: &str, invalid_parameters: &mut Vec<String>)
+    {
+        for (identifier, value) in extract_parameters(line, invalid_parameters)
+        {
+            if identifier == "distanceRange" && !set_parsed(&mut self.distance_range, value.parse().ok())
+            {
+                invalid_parameters.push(format!("{}={}", identifier, value));
+            }
+        }
+    }
+}
+
+/// Stores the information shared by all of the characters, read from the common line
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommonInfo
+{
+    pub line_height: Option<i32>,
+    pub base: Option<i32>,
+    pub scale_w: Option<i32>,
+    pub scale_h: Option<i32>,
+    pub pages: Option<i32>,
+    /// 1 if monochrome characters are packed into each of the color channels of the texture, so
+    /// that each texel holds a part of up to four characters. The chnl of each character says which
+    /// channel it is in
+    pub packed: Option<i32>,
+    /// What each channel of the texture holds: 0 for the character, 1 for its outline, 2 for the
+    /// character and its outline, 3 for zero and 4 for one
+    pub alpha_chnl: Option<i32>,
+    pub red_chnl: Option<i32>,
+    pub green_chnl: Option<i32>,
+    pub blue_chnl: Option<i32>,
+}
+
+impl CommonInfo
+{
+    /// Creates common information with no usable information
+    pub(crate) fn new() -> CommonInfo
+    {
+        CommonInfo
+        {
+            line_height: None,
+            base: None,
+            scale_w: None,
+            scale_h: None,
+            pages: None,
+            packed: None,
+            alpha_chnl: None,
+            red_chnl: None,
+            green_chnl: None,
+            blue_chnl: None,
+        }
+    }
+
+    /// Determines if characters are packed into the color channels of the texture, in which case
+    /// shaders have to sample only the channel of each character. Fonts without a packed
+    /// parameter are not packed
+    pub fn is_packed(&self) -> bool
+    {
+        self.packed.unwrap_or(0) != 0
+    }
+
+    /// Finds the size of the texture atlas from the scaleW and scaleH parameters, which the
+    /// generator sets to the size of the page images
+    pub fn atlas_dimensions(&self) -> Option<AtlasDimensions>
+    {
+        Some(AtlasDimensions { width: self.scale_w?, height: self.scale_h? })
+    }
+
+    /// Parses the given common line. Parameters that are missing or have an invalid value are not set
+    ///
+    /// `invalid_parameters` - where the parameters that could not be read are added to
+    pub(crate) fn from_line(line: &str, invalid_parameters: &mut Vec<String>) -> CommonInfo
+    {
+        let mut common_info = CommonInfo::new();
+
+        // The first split result is the line type, which is not a parameter
+        for split_result in line.split_whitespace().skip(1)
+        {
+            if extract_numeric_value(split_result).is_none()
+            {
+                invalid_parameters.push(split_result.to_string());
+                continue;
+            }
+
+            set_numeric_values!(common_info, split_result,
+                        line_height, "lineHeight",
+                        base, "base",
+                        scale_w, "scaleW",
+                        scale_h, "scaleH",
+                        pages, "pages",
+                        packed, "packed",
+                        alpha_chnl, "alphaChnl",
+                        red_chnl, "redChnl",
+                        green_chnl, "greenChnl",
+                        blue_chnl, "blueChnl");
+        }
+
+        common_info
+    }
+}
+
+/// Describes the texture of a signed distance field font, read from a distanceField line. Shaders
+/// that render such fonts need both the type of field and its range
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceFieldInfo
+{
+    /// The type of distance field, such as sdf, psdf or msdf
+    pub field_type: String,
+    /// The range in pixels of the distances stored in the texture
+    pub distance_range: i32,
+}
+
+impl DistanceFieldInfo
+{
+    /// Parses the given distanceField line. A line without a field type or a distance range does
+    /// not describe the distance field, and so does not result in distance field information.
+    /// Parameters that cannot be read are not reported, as they are reported for the info
+    pub(crate) fn from_line(line: &str) -> Option<DistanceFieldInfo>
+    {
+        let mut field_type = None;
+        let mut distance_range = None;
+
+        for (identifier, value) in extract_parameters(line, &mut Vec::new())
+        {
+            match identifier
+            {
+                "fieldType" => field_type = Some(value.to_string()),
+                "distanceRange" => distance_range = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(DistanceFieldInfo { field_type: field_type?, distance_range: distance_range? })
+    }
+}
+
+/// Stores the texture file that contains the characters of a page, read from a page line
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageInfo
+{
+    pub id: i32,
+    pub file: String,
+}
+
+impl PageInfo
+{
+    /// Parses the given page line. A page without an id or a file cannot be referred to by the
+    /// characters, and so does not result in a page. The file can also be given with image=, as
+    /// written by some versions of the Hiero exporter of libGDX; if a line has both, file= is used
+    ///
+    /// `invalid_parameters` - where the parameters that could not be read are added to
+    pub(crate) fn from_line(line: &str, invalid_parameters: &mut Vec<String>) -> Option<PageInfo>
+    {
+        let mut id = None;
+        let mut file = None;
+        let mut image = None;
+
+        for (identifier, value) in extract_parameters(line, invalid_parameters)
+        {
+            let is_valid = match identifier
+            {
+                // Pages are numbered from zero, so a negative id cannot be referred to by characters
+                "id" => set_parsed(&mut id, value.parse().ok().filter(|x: &i32| *x >= 0)),
+                // Only escaped quotes and backslashes are replaced, so the backslashes of Windows
+                // paths such as fonts\arial.png are kept
+                "file" => set_parsed(&mut file, Some(unescape(value))),
+                "image" => set_parsed(&mut image, Some(unescape(value))),
+                _ => true
+            };
+
+            if !is_valid
+            {
+                invalid_parameters.push(format!("{}={}", identifier, value));
+            }
+        }
+
+        Some(PageInfo { id: id?, file: file.or(image)? })
+    }
+
+    /// Finds the location of the texture file of the page. The file of a page is relative to the
+    /// directory of the font file, unless it is an absolute path, in which case it is used as is.
+    /// Fonts generated on Windows can separate directories with backslashes, which are changed to
+    /// forward slashes so the path can be found on every platform
+    ///
+    /// `fnt_path` - the location of the font file the page was read from
+    pub fn page_path(&self, fnt_path: &Path) -> PathBuf
+    {
+        let file = self.file.replace('\\', "/");
+        let file = Path::new(&file);
+
+        if file.is_absolute()
+        {
+            return file.to_path_buf();
+        }
+
+        match fnt_path.parent()
+        {
+            Some(directory) => directory.join(file),
+            None => file.to_path_buf()
+        }
+    }
+}
+
+/// A value of a duplicated character that was discarded when merging the listings of the character
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharConflict
+{
+    /// The id of the duplicated character
+    pub id: i32,
+    /// The name of the parameter, as written in the font file
+    pub field: &'static str,
+    /// The value of the first listing of the character, which is used
+    pub kept: i32,
+    /// The value of the later listing of the character
+    pub discarded: i32,
+}
+
+/// Stores the amount the horizontal position should be adjusted when the second character
+/// immediately follows the first character, read from a kerning line
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KerningPair
+{
+    pub first: i32,
+    pub second: i32,
+    pub amount: i32,
+}
+
+impl KerningPair
+{
+    /// Parses the given kerning line. The pair is only usable if all of its values are valid
+    ///
+    /// `invalid_parameters` - where the parameters that could not be read are added to
+    pub(crate) fn from_line(line: &str, invalid_parameters: &mut Vec<String>) -> Option<KerningPair>
+    {
+        let mut first = None;
+        let mut second = None;
+        let mut amount = None;
+
+        for (identifier, value) in extract_parameters(line, invalid_parameters)
+        {
+            let is_valid = match identifier
+            {
+                "first" => set_parsed(&mut first, value.parse().ok()),
+                "second" => set_parsed(&mut second, value.parse().ok()),
+                "amount" => set_parsed(&mut amount, value.parse().ok()),
+                _ => true
+            };
+
+            if !is_valid
+            {
+                invalid_parameters.push(format!("{}={}", identifier, value));
+            }
+        }
+
+        Some(KerningPair { first: first?, second: second?, amount: amount? })
+    }
+}
+
+/// Sets the member to the parsed value, returning if the value could be parsed
+fn set_parsed<T>(member: &mut Option<T>, parsed_value: Option<T>) -> bool
+{
+    *member = parsed_value;
+    member.is_some()
+}
+
+/// Converts a value of either 0 or 1 into a boolean
+fn parse_flag(value: &str) -> Option<bool>
+{
+    match value
+    {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None
+    }
+}
+
+/// Converts a comma separated value, such as the padding of the info line, into its numbers. The
+/// number of values must match what is expected
+fn parse_list<const N: usize>(value: &str) -> Option<[i32; N]>
+{
+    let mut list = [0; N];
+    let mut count = 0;
+
+    for (index, x) in value.split(',').enumerate()
+    {
+        *list.get_mut(index)? = x.parse().ok()?;
+        count += 1;
+    }
+
+    if count != N
+    {
+        return None;
+    }
+
+    Some(list)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::{HashMap, HashSet};
+    use std::env;
+    use std::io::Cursor;
+    use std::path::{Path, PathBuf};
+    use crate::{AtlasDimensions, CommonInfo, DistanceFieldInfo, parse_file, parse_reader, parse_str, FontInfo, KerningPair, PageInfo};
+
+    #[test]
+    fn check_glyphs_in_range()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        let ids = font.glyphs_in_range(32, 32).map(|x| x.id).collect::<Vec<Option<i32>>>();
+        assert_eq!(vec![Some(32)], ids);
+
+        let ids = font.glyphs_in_range(100, 200).map(|x| x.id).collect::<Vec<Option<i32>>>();
+        assert_eq!(vec![Some(124), Some(124)], ids);
+
+        assert_eq!(3, font.glyphs_in_range(0, u32::MAX).count());
+        assert_eq!(Some((32, 124)), font.id_range());
+        assert_eq!(None, parse_reader(Cursor::new("info size=32\n"), AtlasDimensions{ width: 512, height: 512 }).unwrap().id_range());
+        assert_eq!(0, font.glyphs_in_range(33, 123).count());
+    }
+
+    #[test]
+    fn check_glyph_size_extremes()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=40\n\
+                        char id=66 x=0 y=0 width=30 height=20\n\
+                        char id=32 x=0 y=0 width=0 height=0\n\
+                        char id=67 x=0 y=0\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        assert_eq!((30, 40), font.max_glyph_size());
+        // The space is not drawn, so it is not the smallest character
+        assert_eq!((10, 20), font.min_glyph_size());
+
+        let font = parse_reader(Cursor::new("char id=32 x=0 y=0 width=0 height=0\n"), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!((0, 0), font.max_glyph_size());
+        assert_eq!((0, 0), font.min_glyph_size());
+    }
+
+    #[test]
+    fn check_advance_of()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        assert_eq!(Some(32), font.advance_of(32));
+        assert_eq!(Some(30), font.advance_of(124));
+        assert_eq!(None, font.advance_of(65));
+    }
+
+    #[test]
+    fn check_glyphs_by_page()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10 page=0\n\
+                        char id=66 x=0 y=0 width=10 height=10 page=1\n\
+                        char id=67 x=10 y=0 width=10 height=10 page=0\n\
+                        char id=68 x=20 y=0 width=10 height=10\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        let pages = font.glyphs_by_page();
+
+        assert_eq!(2, pages.len());
+        assert_eq!(vec![Some(65), Some(67)], pages[&0].iter().map(|x| x.id).collect::<Vec<Option<i32>>>());
+        assert_eq!(vec![Some(66)], pages[&1].iter().map(|x| x.id).collect::<Vec<Option<i32>>>());
+    }
+
+    #[test]
+    fn check_scaled_metrics()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let mut font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // The font size is 57, so drawing at 114 doubles every value
+        assert_eq!(Some(60.0), font.scaled_advance(124, 114.0));
+        assert_eq!(Some((-6.0, 6.0)), font.scaled_offset(124, 114.0));
+        assert_eq!(Some((44.0, 144.0)), font.scaled_size(124, 114.0));
+        assert_eq!(None, font.scaled_advance(65, 114.0));
+
+        font.info.size = Some(-57);
+        assert_eq!(Some(60.0), font.scaled_advance(124, 114.0));
+
+        font.info.size = None;
+        assert_eq!(None, font.scaled_advance(124, 114.0));
+    }
+
+    #[test]
+    fn check_scaled_font()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let mut font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        font.kerning_conflicts.push(KerningPair{ first: 32, second: 124, amount: -3 });
+        let scaled = font.scaled(2.0);
+
+        assert_eq!(font.info.size.map(|x| x * 2), scaled.info.size);
+        assert_eq!(Some([16, 16, 16, 16]), scaled.info.padding);
+        assert_eq!(Some([0, 0]), scaled.info.spacing);
+        assert_eq!(vec![KerningPair{ first: 32, second: 124, amount: -6 }], scaled.kerning_conflicts);
+        assert_eq!(2.0, scaled.draw_scale());
+        assert_eq!(font.common.line_height.map(|x| x * 2), scaled.common.line_height);
+        assert_eq!(font.common.base.map(|x| x * 2), scaled.common.base);
+        assert_eq!(font.kernings.iter().map(|(pair, amount)| (*pair, amount * 2)).collect::<HashMap<(i32, i32), i32>>(), scaled.kernings);
+
+        for (char_info, scaled_char) in font.characters.iter().zip(&scaled.characters)
+        {
+            assert_eq!(char_info.x_advance.map(|x| x * 2), scaled_char.x_advance);
+            assert_eq!(char_info.size().map(|(width, height)| (width as f32 * 2.0, height as f32 * 2.0)), scaled.draw_size(scaled_char));
+            assert_eq!(char_info.offset().map(|(x, y)| (x * 2, y * 2)), scaled_char.offset());
+
+            // The character is still sampled from the same area of the atlas
+            assert_eq!(char_info.position(), scaled_char.position());
+            assert_eq!(char_info.size(), scaled_char.size());
+            assert_eq!(char_info.texture_coordinates, scaled_char.texture_coordinates);
+        }
+
+        // As the areas are unchanged, the scaled font can still be changed and checked the same as
+        // the font it was scaled from
+        let mut patched = scaled.clone();
+        patched.recalculate_texture_coords(AtlasDimensions{ width: 512, height: 512 });
+        assert_eq!(scaled.characters, patched.characters);
+        assert_eq!(font.find_overlaps(), scaled.find_overlaps());
+        let mut unscaled = font.clone();
+        assert!(patched.patch_glyph(124, |x| x.x = Some(100)));
+        assert!(unscaled.patch_glyph(124, |x| x.x = Some(100)));
+        assert_eq!(unscaled.characters[0].texture_coordinates, patched.characters[0].texture_coordinates);
+
+        // Scaled values are rounded to the nearest pixel
+        let font = parse_str("common lineHeight=5\nchar id=65 x=0 y=0 width=3 height=3 xadvance=3", AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        let scaled = font.scaled(0.5);
+        assert_eq!(Some(3), scaled.common.line_height);
+        assert_eq!((Some(3), Some(2)), (scaled.characters[0].width, scaled.characters[0].x_advance));
+        assert_eq!(Some((1.5, 1.5)), scaled.draw_size(&scaled.characters[0]));
+    }
+
+    #[test]
+    fn check_contains()
+    {
+        let contents = "char id=0 x=0 y=0 width=10 height=10\n\
+                        char id=65 x=0 y=0 width=10 height=10\n\
+                        char id=127 x=0 y=0 width=10 height=10\n\
+                        char id=8364 x=0 y=0 width=10 height=10\n\
+                        char id=-1 x=0 y=0 width=10 height=10\n";
+        let mut font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        for id in [0, 65, 127, 8364, -1]
+        {
+            assert!(font.contains(id));
+        }
+
+        for id in [1, 66, 128, 8365, -2]
+        {
+            assert!(!font.contains(id));
+        }
+
+        font.characters.retain(|x| x.id != Some(65));
+        font.rebuild_glyph_ids();
+        assert!(!font.contains(65));
+    }
+
+    #[test]
+    fn check_missing_from()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10\n\
+                        char id=66 x=0 y=0 width=10 height=10\n\
+                        char id=8364 x=0 y=0 width=10 height=10\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        assert!(font.missing_from(&['A', 'B', '€']).is_empty());
+        assert_eq!(vec!['ä', 'C'], font.missing_from(&"BäCAäB€".chars().collect::<Vec<char>>()));
+        assert!(font.missing_from(&[]).is_empty());
+    }
+
+    #[test]
+    fn check_recalculate_texture_coords()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let mut font = parse_file(&test_file, AtlasDimensions{ width: 1, height: 1 }).unwrap();
+
+        let atlas_dimensions = font.common.atlas_dimensions().unwrap();
+        assert_eq!(AtlasDimensions{ width: 512, height: 512 }, atlas_dimensions);
+
+        font.recalculate_texture_coords(atlas_dimensions);
+        assert_eq!(parse_file(&test_file, atlas_dimensions).unwrap().characters, font.characters);
+        assert_eq!(atlas_dimensions, font.atlas_dimensions());
+    }
+
+    #[test]
+    fn check_patch_glyph()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let contents = "char id=65 x=0 y=0 width=10 height=10 xoffset=0 xadvance=12\n\
+                        char id=66 x=10 y=0 width=10 height=10 xoffset=0 xadvance=12\n";
+        let mut font = parse_str(contents, atlas_dimensions).unwrap();
+        let texture_coordinates = font.glyph(65).unwrap().texture_coordinates;
+
+        // Changing only the metrics keeps the texture coordinates
+        assert!(font.patch_glyph(65, |x| { x.x_offset = Some(-1); x.x_advance = Some(11); }));
+        assert_eq!(Some(11), font.advance_of(65));
+        assert_eq!(texture_coordinates, font.glyph(65).unwrap().texture_coordinates);
+
+        // Changing the area finds the texture coordinates again, the same as if it had been read
+        assert!(font.patch_glyph(65, |x| { x.x = Some(20); x.width = Some(8); }));
+        let expected = parse_str("char id=65 x=20 y=0 width=8 height=10", atlas_dimensions).unwrap();
+        assert_eq!(expected.glyph(65).unwrap().texture_coordinates, font.glyph(65).unwrap().texture_coordinates);
+
+        // A changed id is found by its new id, and the other characters are still found
+        assert!(font.patch_glyph(65, |x| x.id = Some(67)));
+        assert!(!font.contains(65));
+        assert!(font.contains(67));
+        assert_eq!(Some(10), font.glyph(66).unwrap().x);
+
+        assert!(!font.patch_glyph(65, |x| x.x_advance = Some(0)));
+
+        // Characters changed without rebuilding the ids are still found
+        font.characters.swap(0, 1);
+        assert!(font.patch_glyph(67, |x| x.x_advance = Some(9)));
+        assert_eq!(Some(9), font.characters[1].x_advance);
+    }
+
+    #[test]
+    fn check_retain_ids()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10\n\
+                        char id=66 x=10 y=0 width=10 height=10\n\
+                        char id=67 x=20 y=0 width=10 height=10\n\
+                        char x=30 y=0 width=10 height=10\n\
+                        kerning first=65 second=66 amount=-1\n\
+                        kerning first=66 second=67 amount=-2\n\
+                        kerning first=67 second=65 amount=-3\n\
+                        kerning first=65 second=67 amount=1\n\
+                        kerning first=65 second=67 amount=2\n";
+        let mut font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        font.retain_ids(&HashSet::from([65, 67, 68]));
+
+        assert_eq!(vec![Some(65), Some(67)], font.characters.iter().map(|x| x.id).collect::<Vec<Option<i32>>>());
+        assert!(font.contains(67));
+        assert!(!font.contains(66));
+
+        // Only pairs where both characters are kept remain
+        assert_eq!(vec![KerningPair{ first: 65, second: 67, amount: 2 }, KerningPair{ first: 67, second: 65, amount: -3 }], font.sorted_kernings());
+        assert_eq!(vec![KerningPair{ first: 65, second: 67, amount: 1 }], font.kerning_conflicts);
+
+        font.retain_ids(&HashSet::from([66]));
+        assert!(font.characters.is_empty());
+        assert!(font.kernings.is_empty());
+        assert!(font.kerning_conflicts.is_empty());
+    }
+
+    #[test]
+    fn check_glyphs_by_atlas_position()
+    {
+        let contents = "char id=65 x=20 y=0 width=10 height=10 page=0\n\
+                        char id=66 x=0 y=10 width=10 height=10 page=0\n\
+                        char id=67 x=0 y=0 width=10 height=10 page=1\n\
+                        char id=68 x=0 y=0 width=10 height=10 page=0\n\
+                        char id=69 width=10 height=10 page=0\n\
+                        char id=70 x=10 y=0 width=10 height=10 page=0\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        let ids = font.glyphs_by_atlas_position().iter().map(|x| x.id.unwrap()).collect::<Vec<i32>>();
+        assert_eq!(vec![68, 70, 65, 66, 69, 67], ids);
+    }
+
+    #[test]
+    fn check_font_lines()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        assert_eq!(Some("Arial".to_string()), font.info.face);
+        assert_eq!(Some(57), font.info.size);
+        assert_eq!(Some(false), font.info.bold);
+        assert_eq!(Some(false), font.info.italic);
+        assert_eq!(Some(String::new()), font.info.charset);
+        assert_eq!(Some(false), font.info.unicode);
+        assert_eq!(Some(100), font.info.stretch_h);
+        assert_eq!(Some(true), font.info.smooth);
+        assert_eq!(Some(1), font.info.aa);
+        assert_eq!(1, font.info.supersample());
+        assert_eq!(Some([8, 8, 8, 8]), font.info.padding);
+        assert_eq!(Some([0, 0]), font.info.spacing);
+        assert_eq!(None, font.info.outline);
+
+        assert_eq!(Some(82), font.common.line_height);
+        assert_eq!(Some(53), font.common.base);
+        assert_eq!(Some(512), font.common.scale_w);
+        assert_eq!(Some(512), font.common.scale_h);
+        assert_eq!(Some(1), font.common.pages);
+
+        assert_eq!(vec![PageInfo{ id: 0, file: "arial.png".to_string() }], font.pages);
+        assert_eq!(vec![KerningPair{ first: 32, second: 124, amount: -1 }, KerningPair{ first: 124, second: 32, amount: 2 }], font.sorted_kernings());
+        assert!(font.kerning_conflicts.is_empty());
+        assert_eq!(3, font.characters.len());
+    }
+
+    #[test]
+    fn check_duplicate_kernings()
+    {
+        let contents = "kerning first=32 second=65 amount=-1\n\
+                        kerning first=32 second=65 amount=-1\n\
+                        kerning first=65 second=86 amount=-3\n\
+                        kerning first=32 second=65 amount=-2\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        assert_eq!(2, font.kernings.len());
+        assert_eq!(-2, font.kerning_amount(32, 65));
+        assert_eq!(-3, font.kerning_amount(65, 86));
+        assert_eq!(0, font.kerning_amount(86, 65));
+
+        // Repeating a pair with the same amount is not a conflict
+        assert_eq!(vec![KerningPair{ first: 32, second: 65, amount: -1 }], font.kerning_conflicts);
+    }
+
+    #[test]
+    fn check_negative_size()
+    {
+        let font_info = FontInfo::from_line("info face=\"Arial\" size=-32", &mut Vec::new());
+        assert_eq!(Some(-32), font_info.size);
+        assert_eq!(Some(32), font_info.size_abs());
+        assert_eq!(Some(true), font_info.matches_char_height());
+
+        let font_info = FontInfo::from_line("info face=\"Arial\" size=32", &mut Vec::new());
+        assert_eq!(Some(32), font_info.size_abs());
+        assert_eq!(Some(false), font_info.matches_char_height());
+
+        assert_eq!(None, FontInfo::new().size_abs());
+    }
+
+    #[test]
+    fn check_supersample()
+    {
+        let font_info = FontInfo::from_line("info face=\"Arial\" size=32 aa=4", &mut Vec::new());
+        assert_eq!(Some(4), font_info.aa);
+        assert_eq!(4, font_info.supersample());
+
+        // Not anti-aliased, and not recorded at all, are both the same as no supersampling
+        assert_eq!(1, FontInfo::from_line("info face=\"Arial\" size=32 aa=0", &mut Vec::new()).supersample());
+        assert_eq!(1, FontInfo::new().supersample());
+
+        let mut invalid_parameters = Vec::new();
+        assert_eq!(None, FontInfo::from_line("info aa=-2", &mut invalid_parameters).aa);
+        assert_eq!(vec!["aa=-2".to_string()], invalid_parameters);
+    }
+
+    #[test]
+    fn check_face_with_escaped_quotes()
+    {
+        let font_info = FontInfo::from_line("info face=\"He said \\\"hi\\\"\" size=32 charset=\"\"", &mut Vec::new());
+        assert_eq!(Some("He said \"hi\"".to_string()), font_info.face);
+        assert_eq!(Some(32), font_info.size);
+        assert_eq!(Some("".to_string()), font_info.charset);
+
+        // The escaped quotes are kept when the font is written and read again
+        let mut font = parse_str("info face=\"\\\"Quoted\\\" Sans\" size=32", AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(Some("\"Quoted\" Sans".to_string()), font.info.face);
+
+        let mut written = Vec::new();
+        font.write_text(&mut written).unwrap();
+        font = parse_reader(written.as_slice(), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(Some("\"Quoted\" Sans".to_string()), font.info.face);
+    }
+
+    #[test]
+    fn check_quoted_face_with_spaces()
+    {
+        let font_info = FontInfo::from_line("info face=\"Times New Roman\" size=32 padding=1,2,3", &mut Vec::new());
+        assert_eq!(Some("Times New Roman".to_string()), font_info.face);
+        assert_eq!(Some(32), font_info.size);
+
+        // Padding requires four values
+        assert_eq!(None, font_info.padding);
+    }
+
+    #[test]
+    fn check_hiero_page_line()
+    {
+        let page = PageInfo::from_line("page id=0 image=\"hiero font.png\"", &mut Vec::new()).unwrap();
+        assert_eq!(PageInfo{ id: 0, file: "hiero font.png".to_string() }, page);
+
+        let page = PageInfo::from_line("page image=\"other.png\" id=1 file=\"font.png\"", &mut Vec::new()).unwrap();
+        assert_eq!(PageInfo{ id: 1, file: "font.png".to_string() }, page);
+
+        assert_eq!(None, PageInfo::from_line("page id=0", &mut Vec::new()));
+    }
+
+    #[test]
+    fn check_page_path()
+    {
+        let page = PageInfo{ id: 0, file: "arial.png".to_string() };
+        assert_eq!(PathBuf::from("fonts/ui/arial.png"), page.page_path(Path::new("fonts/ui/arial.fnt")));
+        assert_eq!(PathBuf::from("arial.png"), page.page_path(Path::new("arial.fnt")));
+
+        let page = PageInfo::from_line("page id=0 file=\"sub\\arial_0.png\"", &mut Vec::new()).unwrap();
+        assert_eq!(PathBuf::from("fonts/sub/arial_0.png"), page.page_path(Path::new("fonts/arial.fnt")));
+        assert_eq!(Some("arial_0.png"), page.page_path(Path::new("fonts/arial.fnt")).file_name().and_then(|x| x.to_str()));
+
+        let absolute_file = env::current_dir().unwrap().join("textures").join("arial.png");
+        let page = PageInfo{ id: 0, file: absolute_file.to_str().unwrap().to_string() };
+        assert_eq!(absolute_file, page.page_path(Path::new("fonts/ui/arial.fnt")));
+    }
+
+    #[test]
+    fn check_packed_channels()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(!font.common.is_packed());
+        assert_eq!((Some(0), Some(1), Some(0), Some(0), Some(0)), (font.common.packed, font.common.alpha_chnl, font.common.red_chnl, font.common.green_chnl, font.common.blue_chnl));
+
+        let common_info = CommonInfo::from_line("common lineHeight=82 packed=1 alphaChnl=0 redChnl=4 greenChnl=4 blueChnl=4", &mut Vec::new());
+        assert!(common_info.is_packed());
+        assert_eq!((Some(0), Some(4), Some(4), Some(4)), (common_info.alpha_chnl, common_info.red_chnl, common_info.green_chnl, common_info.blue_chnl));
+
+        // Fonts without the packed parameter are not packed
+        let common_info = CommonInfo::from_line("common lineHeight=82", &mut Vec::new());
+        assert!(!common_info.is_packed());
+        assert_eq!(None, common_info.alpha_chnl);
+    }
+
+    #[test]
+    fn check_distance_range()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+
+        let contents = "info face=\"Arial\" size=42\ncommon lineHeight=48\ndistanceField fieldType=msdf distanceRange=4\n";
+        let font = parse_reader(Cursor::new(contents), atlas_dimensions).unwrap();
+        assert_eq!(Some(4), font.info.distance_range);
+        assert_eq!(Some(DistanceFieldInfo{ field_type: "msdf".to_string(), distance_range: 4 }), font.distance_field);
+
+        let font = parse_reader(Cursor::new("info face=\"Arial\" distanceRange=8\n"), atlas_dimensions).unwrap();
+        assert_eq!(Some(8), font.info.distance_range);
+        assert_eq!(None, font.distance_field);
+
+        // Both the type and range are needed to describe the distance field
+        let font = parse_reader(Cursor::new("info face=\"Arial\"\ndistanceField distanceRange=4\n"), atlas_dimensions).unwrap();
+        assert_eq!((Some(4), None), (font.info.distance_range, font.distance_field));
+
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, atlas_dimensions).unwrap();
+        assert_eq!((None, None), (font.info.distance_range, font.distance_field));
+    }
+
+    #[test]
+    fn check_unicode_flag()
+    {
+        let contents = "info face=\"Arial\" charset=\"\" unicode=1\nchar id=228 x=0 y=0 width=10 height=10\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(Some(true), font.info.unicode);
+        assert_eq!(Some('ä'), font.char_of(&font.characters[0]));
+
+        // Without unicode, id 228 is an index into the ANSI charset rather than a code point
+        let contents = "info face=\"Arial\" charset=\"ANSI\" unicode=0\nchar id=228 x=0 y=0 width=10 height=10\n";
+        let font = parse_reader(Cursor::new(contents), AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(Some(false), font.info.unicode);
+        assert_eq!(Some("ANSI".to_string()), font.info.charset);
+        assert_eq!(None, font.char_of(&font.characters[0]));
+        assert_eq!(Some('ä'), font.characters[0].as_char());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::CharacterInfo;
+
+/// The offsets and advance of a character as floats, for fonts from distance field exporters that
+/// write them with fractions of a pixel, such as xadvance=12.5. Values that are not known are None
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct FractionalMetrics
+{
+    pub x_offset: Option<f32>,
+    pub y_offset: Option<f32>,
+    pub x_advance: Option<f32>,
+}
+
+impl FractionalMetrics
+{
+    /// Creates a copy of the metrics with each value multiplied by the given factor
+    pub(crate) fn scaled(&self, factor: f32) -> FractionalMetrics
+    {
+        FractionalMetrics
+        {
+            x_offset: self.x_offset.map(|x| x * factor),
+            y_offset: self.y_offset.map(|x| x * factor),
+            x_advance: self.x_advance.map(|x| x * factor),
+        }
+    }
+}
+
+impl CharacterInfo
+{
+    /// Finds the offsets and advance of the character as floats. Values that were read with a
+    /// fraction keep it, while the others are the integer values of the character
+    pub fn metrics_f32(&self) -> FractionalMetrics
+    {
+        let fractional = self.fractional.unwrap_or_default();
+
+        FractionalMetrics
+        {
+            x_offset: fractional.x_offset.or(self.x_offset.map(|x| x as f32)),
+            y_offset: fractional.y_offset.or(self.y_offset.map(|x| x as f32)),
+            x_advance: fractional.x_advance.or(self.x_advance.map(|x| x as f32)),
+        }
+    }
+}
+
+/// Reads the parameter if it is an offset or the advance with a fraction, such as xadvance=12.5.
+/// The integer value of the character is the value rounded to the nearest pixel, so that code which
+/// only uses integers still gets the closest value. Returns if the parameter was read
+///
+/// `parameter` - the memberVariable-value string extracted from the char line
+pub(crate) fn fill_in_fractional(char_info: &mut CharacterInfo, parameter: &str) -> bool
+{
+    let (identifier, value) = match parameter.split_once('=')
+    {
+        Some((identifier, value)) => match value.parse::<f32>()
+            {
+                Ok(value) if value.is_finite() && value.abs() < i32::MAX as f32 => (identifier, value),
+                _ => return false
+            },
+        None => return false
+    };
+
+    if !matches!(identifier, "xoffset" | "yoffset" | "xadvance")
+    {
+        return false;
+    }
+
+    let fractional = char_info.fractional.get_or_insert_with(FractionalMetrics::default);
+    let rounded = Some(value.round() as i32);
+
+    match identifier
+    {
+        "xoffset" =>
+            {
+                fractional.x_offset = Some(value);
+                char_info.x_offset = rounded;
+            },
+        "yoffset" =>
+            {
+                fractional.y_offset = Some(value);
+                char_info.y_offset = rounded;
+            },
+        _ =>
+            {
+                fractional.x_advance = Some(value);
+                char_info.x_advance = rounded;
+            }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{AtlasDimensions, FractionalMetrics, parse_reader_with_diagnostics, parse_reader_with_options, ParseOptions};
+
+    #[test]
+    fn check_fractional_values()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let contents = "common lineHeight=32 base=26\n\
+                        char id=65 x=0 y=0 width=10 height=12 xoffset=-0.75 yoffset=4 advance=12.5\n\
+                        char id=66 x=0 y=0 width=10 height=12 xoffset=1 yoffset=2 advance=9 page=0.5\n";
+
+        let mut options = ParseOptions{ fractional_values: true, ..ParseOptions::default() };
+        options.field_aliases.insert("advance".to_string(), "xadvance".to_string());
+        let (font, errors) = parse_reader_with_diagnostics(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+
+        // The alias is applied before the value is read, so the advance keeps its fraction
+        let glyph = font.glyph(65).unwrap();
+        assert_eq!(Some(-1), glyph.x_offset);
+        assert_eq!(Some(13), glyph.x_advance);
+        assert_eq!(Some(FractionalMetrics{ x_offset: Some(-0.75), y_offset: None, x_advance: Some(12.5) }), glyph.fractional);
+        assert_eq!(FractionalMetrics{ x_offset: Some(-0.75), y_offset: Some(4.0), x_advance: Some(12.5) }, glyph.metrics_f32());
+
+        // Only the offsets and advance can have fractions
+        let glyph = font.glyph(66).unwrap();
+        assert_eq!(None, glyph.fractional);
+        assert_eq!(None, glyph.page);
+        assert_eq!(1, errors.len());
+
+        // The fractions are written back out, and scaled along with the rest of the font
+        let mut written = Vec::new();
+        font.write_text(&mut written).unwrap();
+        assert_eq!(font.characters, parse_reader_with_options(written.as_slice(), atlas_dimensions, &options).unwrap().characters);
+        let scaled = font.scaled(1.5);
+        assert_eq!(Some(19), scaled.glyph(65).unwrap().x_advance);
+        assert_eq!(Some(18.75), scaled.glyph(65).unwrap().metrics_f32().x_advance);
+
+        // Without the option, fractions cannot be read
+        options.fractional_values = false;
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(None, font.glyph(65).unwrap().x_advance);
+        assert_eq!(FractionalMetrics{ x_offset: None, y_offset: Some(4.0), x_advance: None }, font.glyph(65).unwrap().metrics_f32());
+    }
+}
@@ -0,0 +1,873 @@
+use crate::{CharacterInfo, Font, Uv, BOTTOM_LEFT_INDEX, BOTTOM_RIGHT_INDEX, TOP_LEFT_INDEX, TOP_RIGHT_INDEX};
+
+/// The id of the space character, used to find the distance between tab stops
+const SPACE_ID: i32 = 32;
+
+/// The distance between two tab stops
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TabWidth
+{
+    /// A fixed distance in pixels
+    Pixels(i32),
+    /// A multiple of the advance of the space character
+    Spaces(i32),
+}
+
+/// What the vertical positions of laid out characters are relative to
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VerticalAnchor
+{
+    /// The top of the first line of text
+    Top,
+    /// The baseline of the first line of text, so that text of different fonts lines up when
+    /// laid out at the same position. Characters above the baseline have a negative position
+    Baseline,
+}
+
+/// Which way the characters of a line of text are laid out
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Direction
+{
+    /// Each character is to the right of the previous one, starting at the left of the text
+    LeftToRight,
+    /// Each character is to the left of the previous one, starting at the right of the text, as
+    /// used by Arabic and Hebrew. The characters are expected to already be in the order they are
+    /// read and in the form they are drawn; no reordering or shaping is done. Positions to the
+    /// left of the start of the text are negative
+    RightToLeft,
+}
+
+/// How spaces and tabs that follow each other are laid out
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Whitespace
+{
+    /// Every space and tab moves the position of the next character, as is wanted for code
+    Preserve,
+    /// A run of spaces and tabs moves the position of the next character by a single space, as is
+    /// wanted for prose
+    Collapse,
+}
+
+/// Where each line of laid out text is placed within the width of the widest line
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Alignment
+{
+    /// The left sides of the lines line up
+    Left,
+    /// The middles of the lines line up. Lines that cannot be centered exactly are a pixel closer
+    /// to the left
+    Center,
+    /// The right sides of the lines line up
+    Right,
+}
+
+/// Changes how text is positioned by the layout functions
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutOptions
+{
+    /// The distance between tab stops. A tab moves the position of the next character to the next
+    /// tab stop rather than being drawn. Defaults to the advance of four spaces
+    pub tab_width: TabWidth,
+    /// The distance between the tops of two lines of text, to space lines closer together or
+    /// further apart than the font does. Defaults to None, which uses the line height of the font
+    pub line_height: Option<i32>,
+    /// What the vertical positions of the characters are relative to. Defaults to the top
+    pub anchor: VerticalAnchor,
+    /// Which way the characters of each line are laid out. Defaults to left to right
+    pub direction: Direction,
+    /// How spaces and tabs that follow each other are laid out. Defaults to keeping all of them
+    pub whitespace: Whitespace,
+    /// Where each line is placed within the width of the widest line. Whichever the direction,
+    /// the widest line is where it would be without alignment. Defaults to None, where each line
+    /// starts at the start of the text, so left to right lines are aligned left and right to left
+    /// lines are aligned right
+    pub alignment: Option<Alignment>,
+    /// If true, the pen moves by the whole pixel offsets and advances of the characters, so every
+    /// character is drawn at a whole pixel and bitmap characters are not blurred across pixels.
+    /// Otherwise the pen moves by the fractional offsets and advances of metrics_f32, as is wanted
+    /// for distance field fonts with subpixel advances, and the exact positions can have fractions.
+    /// Defaults to true
+    pub snap_to_pixel: bool,
+}
+
+impl Default for LayoutOptions
+{
+    fn default() -> LayoutOptions
+    {
+        LayoutOptions
+        {
+            tab_width: TabWidth::Spaces(4),
+            line_height: None,
+            anchor: VerticalAnchor::Top,
+            direction: Direction::LeftToRight,
+            whitespace: Whitespace::Preserve,
+            alignment: None,
+            snap_to_pixel: true,
+        }
+    }
+}
+
+/// A character of laid out text, along with where it should be drawn
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionedGlyph<'a>
+{
+    pub glyph: &'a CharacterInfo,
+    /// The position of the top left corner of the character, in pixels relative to the left of the
+    /// text and the anchor of the layout options. As with the atlas, y increases downward. When
+    /// the layout options do not snap to pixels, this is the exact position rounded to the nearest
+    /// pixel
+    pub x: i32,
+    pub y: i32,
+    /// The exact position of the top left corner of the character, the same as x and y when the
+    /// layout options snap to pixels. Otherwise it is found with the fractional offsets and
+    /// advances of the characters
+    pub x_f32: f32,
+    pub y_f32: f32,
+}
+
+/// A character of shaped text, along with how far the pen moves after it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapedGlyph<'a>
+{
+    pub glyph: &'a CharacterInfo,
+    /// The advance of the character, adjusted by the kerning between it and the next character
+    pub advance: i32,
+}
+
+/// A character of laid out text as a quad that is ready to be drawn, with the position of each of
+/// its corners in normalized device coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct NdcQuad<'a>
+{
+    pub glyph: &'a CharacterInfo,
+    /// The position of each corner, from -1 to 1 across the screen with y increasing upward as in
+    /// OpenGL. The corners are in the same order as the texture coordinates
+    pub positions: [(f32, f32); 4],
+    /// The texture coordinates of the character, so that the quad can be drawn without it
+    pub texture_coordinates: [Uv; 4],
+}
+
+impl Font
+{
+    /// Finds where each character of the text should be drawn. Each line of text starts below the
+    /// previous one by the line height, characters are moved closer or further apart
+    /// according to the kerning pairs, and tabs advance to the next tab stop. Characters that the
+    /// font does not have are skipped. Characters without an area of the atlas are not positioned,
+    /// but still advance the next character. The positioned characters borrow the characters of
+    /// the font rather than copying them
+    ///
+    /// `text` - the text to position the characters of
+    /// `options` - changes how the text is positioned
+    pub fn layout(&self, text: &str, options: &LayoutOptions) -> Vec<PositionedGlyph<'_>>
+    {
+        let mut positioned_glyphs = Vec::new();
+        self.layout_into(text, options, &mut positioned_glyphs);
+        positioned_glyphs
+    }
+
+    /// Finds where each character of the text should be drawn, the same as layout, but places the
+    /// positioned characters into the given buffer. The buffer is cleared first; reusing the same
+    /// buffer every frame avoids allocating once it is large enough
+    ///
+    /// `text` - the text to position the characters of
+    /// `options` - changes how the text is positioned
+    /// `positioned_glyphs` - where the positioned characters are placed
+    pub fn layout_into<'a>(&'a self, text: &str, options: &LayoutOptions, positioned_glyphs: &mut Vec<PositionedGlyph<'a>>)
+    {
+        let line_height = options.line_height.or(self.common.line_height).unwrap_or(0);
+        let anchor_y = match options.anchor
+        {
+            VerticalAnchor::Top => 0,
+            VerticalAnchor::Baseline => self.baseline_offset()
+        };
+
+        positioned_glyphs.clear();
+        let block_width = match options.alignment
+        {
+            Some(_) => self.block_bounds_with(text, options).0,
+            None => 0
+        };
+        let mut pen = Pen::new(self, options);
+
+        for (line_index, line) in text.split('\n').enumerate()
+        {
+            let pen_y = line_index as i32 * line_height - anchor_y;
+            let line_x = self.line_alignment_offset(line, block_width, options);
+            pen.start_line();
+
+            for character in line.chars()
+            {
+                // Characters without an area of the atlas, such as combining marks, still move the
+                // pen but are not drawn
+                if let Some((glyph, pen_x)) = pen.advance(character).filter(|(glyph, _)| glyph.is_renderable())
+                {
+                    let (x_offset, y_offset) = match options.snap_to_pixel
+                    {
+                        true => (glyph.x_offset.unwrap_or(0) as f32, glyph.y_offset.unwrap_or(0) as f32),
+                        false => (glyph.metrics_f32().x_offset.unwrap_or(0.0), glyph.metrics_f32().y_offset.unwrap_or(0.0))
+                    };
+                    let x_f32 = line_x as f32 + pen_x + x_offset;
+                    let y_f32 = pen_y as f32 + y_offset;
+
+                    positioned_glyphs.push(PositionedGlyph
+                    {
+                        glyph,
+                        x: x_f32.round() as i32,
+                        y: y_f32.round() as i32,
+                        x_f32,
+                        y_f32,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Lays out the text, the same as layout, and turns each character into a quad in normalized
+    /// device coordinates for a screen of the given size. The corners of the quads are at the exact
+    /// positions of layout, so they are on the edges of the pixels of the screen when the layout
+    /// options snap to pixels. Characters without a width
+    /// or height have a quad without an area. If the screen has no area, there are no quads
+    ///
+    /// `text` - the text to position the characters of
+    /// `options` - changes how the text is positioned
+    /// `screen_size` - the width and height of the screen in pixels
+    /// `start` - the position on the screen the layout is relative to, in pixels from the top left
+    ///           of the screen with y increasing downward
+    pub fn layout_ndc(&self, text: &str, options: &LayoutOptions, screen_size: (i32, i32), start: (i32, i32)) -> Vec<NdcQuad<'_>>
+    {
+        let (screen_width, screen_height) = screen_size;
+        if screen_width <= 0 || screen_height <= 0
+        {
+            return Vec::new();
+        }
+
+        // The screen goes from -1 to 1, and the y axis is flipped the same as texture coordinates
+        let to_ndc = |x: f32, y: f32| (2.0 * x / screen_width as f32 - 1.0, 1.0 - 2.0 * y / screen_height as f32);
+
+        self.layout(text, options).into_iter().map(|positioned|
+            {
+                let left = start.0 as f32 + positioned.x_f32;
+                let top = start.1 as f32 + positioned.y_f32;
+                let (width, height) = self.draw_size(positioned.glyph).unwrap_or((0.0, 0.0));
+                let right = left + width;
+                let bottom = top + height;
+
+                let mut positions = [(0.0, 0.0); 4];
+                positions[TOP_LEFT_INDEX] = to_ndc(left, top);
+                positions[TOP_RIGHT_INDEX] = to_ndc(right, top);
+                positions[BOTTOM_LEFT_INDEX] = to_ndc(left, bottom);
+                positions[BOTTOM_RIGHT_INDEX] = to_ndc(right, bottom);
+
+                NdcQuad { glyph: positioned.glyph, positions, texture_coordinates: positioned.glyph.texture_coordinates }
+            }).collect()
+    }
+
+    /// Finds the characters of the text along with their advances, including the kerning with the
+    /// character that follows each of them. Unlike layout, no positions are found; the characters
+    /// are given in order for drawing code that positions them itself. Characters that the font
+    /// does not have, including line breaks and tabs, are skipped, and the characters on either side
+    /// of them are not kerned with each other
+    ///
+    /// `text` - the text to shape
+    pub fn shape<'a>(&'a self, text: &'a str) -> impl Iterator<Item = ShapedGlyph<'a>> + 'a
+    {
+        // The characters are peeked before the missing ones are skipped, so that characters on
+        // either side of a missing character are not kerned with each other
+        let mut characters = text.chars().peekable();
+
+        std::iter::from_fn(move ||
+            {
+                let glyph = characters.by_ref().find_map(|x| self.glyph(x as i32))?;
+                let kerning = match (glyph.id, characters.peek().and_then(|&x| self.glyph(x as i32)).and_then(|x| x.id))
+                {
+                    (Some(first), Some(second)) => self.kerning_amount(first, second),
+                    _ => 0
+                };
+
+                Some(ShapedGlyph { glyph, advance: glyph.x_advance.unwrap_or(0) + kerning })
+            })
+    }
+
+    /// Finds the distance in pixels from the top of a line of text to its baseline, which is what
+    /// the characters of the font sit on
+    pub fn baseline_offset(&self) -> i32
+    {
+        self.common.base.unwrap_or(0)
+    }
+
+    /// Finds the distance in pixels between the tops of two lines of text, from the common line
+    /// of the font. A font without a line height has a line height of zero
+    pub fn line_height(&self) -> i32
+    {
+        self.common.line_height.unwrap_or(0)
+    }
+
+    /// Finds the distance in pixels from the top of a line of text down to the baseline, which is
+    /// the base of the font. The same as baseline_offset
+    pub fn ascent(&self) -> i32
+    {
+        self.baseline_offset()
+    }
+
+    /// Finds the distance in pixels from the baseline down to the top of the next line of text,
+    /// which is the line height minus the base. The ascent and descent add up to the line height
+    pub fn descent(&self) -> i32
+    {
+        self.line_height() - self.ascent()
+    }
+
+    /// Finds the width of the text in pixels, which is the sum of the advances of its characters
+    /// (including kerning and tabs). For text with more than one line, the width of the widest line
+    /// is used
+    ///
+    /// `text` - the text to find the width of
+    pub fn measure_width(&self, text: &str) -> i32
+    {
+        let options = LayoutOptions::default();
+        text.split('\n').map(|line| self.line_width(line, &options)).max().unwrap_or(0)
+    }
+
+    /// Finds the size of the box that the text occupies, as the width and height in pixels. The
+    /// width is that of the widest line, and the height is the number of lines multiplied by the
+    /// line height of the font
+    ///
+    /// `text` - the text to find the size of, where lines are separated by '\n'
+    pub fn text_bounds(&self, text: &str) -> (i32, i32)
+    {
+        self.block_bounds(text)
+    }
+
+    /// Finds the size of the block of lines of the text, as the width of the widest line and the
+    /// number of lines multiplied by the line height of the font, in pixels. The same as
+    /// text_bounds; see block_bounds_with for text laid out with other options
+    ///
+    /// `text` - the text to find the size of, where lines are separated by '\n'
+    pub fn block_bounds(&self, text: &str) -> (i32, i32)
+    {
+        self.block_bounds_with(text, &LayoutOptions::default())
+    }
+
+    /// Finds the size of the box that the text occupies when laid out with the given options, as
+    /// the width and height in pixels. Unlike the block bounds, the width of each line follows the
+    /// tab width, direction and whitespace of the options, and the height uses their line height,
+    /// so that a box sized to it fits the glyphs of the layout functions
+    ///
+    /// `text` - the text to find the size of, where lines are separated by '\n'
+    /// `options` - the options the text is laid out with
+    pub fn block_bounds_with(&self, text: &str, options: &LayoutOptions) -> (i32, i32)
+    {
+        if text.is_empty()
+        {
+            return (0, 0);
+        }
+
+        // Right to left lines move the pen to the left, with the same distance as their width
+        let width = text.split('\n').map(|line| self.line_width(line, options).abs()).max().unwrap_or(0);
+        let line_count = text.split('\n').count() as i32;
+        (width, line_count * options.line_height.or(self.common.line_height).unwrap_or(0))
+    }
+
+    /// Finds how far from the left of a box the text has to start to be centered in it
+    /// horizontally, in pixels. Text wider than the box has a negative offset, so that it overflows
+    /// both sides equally
+    ///
+    /// `text` - the text to center, where lines are separated by '\n'
+    /// `box_width` - the width of the box in pixels
+    pub fn center_offset(&self, text: &str, box_width: i32) -> i32
+    {
+        (box_width - self.measure_width(text)) / 2
+    }
+
+    /// Finds how far from the top of a box the text has to start to be centered in it vertically,
+    /// in pixels, using the line height of the font for each line of text
+    ///
+    /// `text` - the text to center, where lines are separated by '\n'
+    /// `box_height` - the height of the box in pixels
+    pub fn vertical_center_offset(&self, text: &str, box_height: i32) -> i32
+    {
+        (box_height - self.text_bounds(text).1) / 2
+    }
+
+    /// Finds how far the line is moved to the right to be aligned within a block of text of the
+    /// given width. The block starts at the left of the text when laying out left to right, and
+    /// ends at it when laying out right to left
+    fn line_alignment_offset(&self, line: &str, block_width: i32, options: &LayoutOptions) -> i32
+    {
+        let alignment = match options.alignment
+        {
+            Some(alignment) => alignment,
+            None => return 0
+        };
+
+        let width = self.line_width(line, options).abs();
+        let (block_left, line_left) = match options.direction
+        {
+            Direction::LeftToRight => (0, 0),
+            Direction::RightToLeft => (-block_width, -width)
+        };
+
+        let aligned_left = match alignment
+        {
+            Alignment::Left => block_left,
+            Alignment::Center => block_left + (block_width - width) / 2,
+            Alignment::Right => block_left + block_width - width
+        };
+
+        aligned_left - line_left
+    }
+
+    /// Finds the sum of the advances of the characters of a single line
+    fn line_width(&self, line: &str, options: &LayoutOptions) -> i32
+    {
+        let mut pen = Pen::new(self, options);
+
+        for character in line.chars()
+        {
+            pen.advance(character);
+        }
+
+        // Fractional advances are added up before rounding, the same as the positions of layout
+        pen.x.round() as i32
+    }
+}
+
+/// Keeps track of where the next character of a line of laid out text goes
+struct Pen<'a>
+{
+    font: &'a Font,
+    tab_width: i32,
+    direction: Direction,
+    whitespace: Whitespace,
+    snap_to_pixel: bool,
+    /// The position of the pen, which only has whole pixels when snapping to pixels
+    x: f32,
+    previous_id: Option<i32>,
+    /// If the previous character was a space or tab, to collapse the ones that follow it
+    after_whitespace: bool,
+}
+
+impl<'a> Pen<'a>
+{
+    fn new(font: &'a Font, options: &LayoutOptions) -> Pen<'a>
+    {
+        let tab_width = match options.tab_width
+        {
+            TabWidth::Pixels(pixels) => pixels,
+            TabWidth::Spaces(spaces) => spaces * font.advance_of(SPACE_ID).unwrap_or(0)
+        };
+
+        Pen { font, tab_width, direction: options.direction, whitespace: options.whitespace, snap_to_pixel: options.snap_to_pixel, x: 0.0, previous_id: None, after_whitespace: false }
+    }
+
+    /// Moves the pen back to the start of a line
+    fn start_line(&mut self)
+    {
+        self.x = 0.0;
+        self.previous_id = None;
+        self.after_whitespace = false;
+    }
+
+    /// Moves the pen past the character. If the character is drawn, it is returned along with the
+    /// position of the pen at which it is drawn. When laying out right to left, the pen moves left
+    /// past the character before it is drawn, so that the character is drawn to the left of the
+    /// previous one. The x offset still moves the character right, as the characters of the atlas
+    /// are not mirrored
+    fn advance(&mut self, character: char) -> Option<(&'a CharacterInfo, f32)>
+    {
+        let is_whitespace = character == ' ' || character == '\t';
+        let after_whitespace = std::mem::replace(&mut self.after_whitespace, is_whitespace);
+
+        // A collapsed run of whitespace is a single space, however long it is
+        let character = match (self.whitespace, is_whitespace, after_whitespace)
+        {
+            (Whitespace::Collapse, true, true) => return None,
+            (Whitespace::Collapse, true, false) => ' ',
+            _ => character
+        };
+
+        if character == '\t'
+        {
+            // A tab always moves forward, even when the pen is already at a tab stop
+            if self.tab_width > 0
+            {
+                let tab_width = self.tab_width as f32;
+                self.x = match self.direction
+                {
+                    Direction::LeftToRight => ((self.x / tab_width).floor() + 1.0) * tab_width,
+                    Direction::RightToLeft => -(((-self.x) / tab_width).floor() + 1.0) * tab_width
+                };
+            }
+
+            self.previous_id = None;
+            return None;
+        }
+
+        let id = character as i32;
+        let glyph = match self.font.glyph(id)
+        {
+            Some(glyph) => glyph,
+            None =>
+                {
+                    // Characters on either side of a missing character are not kerned with each other
+                    self.previous_id = None;
+                    return None;
+                }
+        };
+        let kerning = self.previous_id.map(|previous_id| self.font.kerning_amount(previous_id, id)).unwrap_or(0) as f32;
+        let advance = match self.snap_to_pixel
+        {
+            true => glyph.x_advance.unwrap_or(0) as f32,
+            false => glyph.metrics_f32().x_advance.unwrap_or(0.0)
+        };
+        self.previous_id = Some(id);
+
+        match self.direction
+        {
+            Direction::LeftToRight =>
+                {
+                    self.x += kerning;
+                    let glyph_pen_x = self.x;
+                    self.x += advance;
+                    Some((glyph, glyph_pen_x))
+                },
+            Direction::RightToLeft =>
+                {
+                    self.x -= kerning + advance;
+                    Some((glyph, self.x))
+                }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{Alignment, AtlasDimensions, Direction, Font, LayoutOptions, Whitespace, parse_file, parse_reader_with_options, parse_str, ParseOptions, TabWidth, VerticalAnchor, BOTTOM_LEFT_INDEX, BOTTOM_RIGHT_INDEX, TOP_LEFT_INDEX, TOP_RIGHT_INDEX};
+
+    fn load_font() -> Font
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap()
+    }
+
+    fn x_positions(font: &Font, text: &str, options: &LayoutOptions) -> Vec<i32>
+    {
+        font.layout(text, options).iter().map(|x| x.x).collect()
+    }
+
+    #[test]
+    fn check_layout()
+    {
+        let font = load_font();
+        let positioned_glyphs = font.layout("| |\n|", &LayoutOptions::default());
+
+        // The bar has an x offset of -3 and an advance of 30, the space an advance of 32
+        assert_eq!(4, positioned_glyphs.len());
+        assert_eq!(vec![-3, 30, 59, -3], positioned_glyphs.iter().map(|x| x.x).collect::<Vec<i32>>());
+        assert_eq!(vec![3, 53, 3, 85], positioned_glyphs.iter().map(|x| x.y).collect::<Vec<i32>>());
+        assert_eq!(Some(124), positioned_glyphs[0].glyph.id);
+
+        // Characters the font does not have are skipped
+        assert_eq!(vec![-3, 27], x_positions(&font, "|A|", &LayoutOptions::default()));
+    }
+
+    #[test]
+    fn check_advance_only_glyphs()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10 xadvance=12\n\
+                        char id=66 xadvance=5\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(!font.glyph(66).unwrap().is_renderable());
+
+        // The character without an area is not drawn, but the pen still moves past it
+        let positioned_glyphs = font.layout("ABA", &LayoutOptions::default());
+        assert_eq!(vec![(Some(65), 0), (Some(65), 17)], positioned_glyphs.iter().map(|x| (x.glyph.id, x.x)).collect::<Vec<(Option<i32>, i32)>>());
+        assert_eq!(2, font.layout_ndc("ABA", &LayoutOptions::default(), (100, 100), (0, 0)).len());
+        assert_eq!(29, font.measure_width("ABA"));
+    }
+
+    #[test]
+    fn check_line_height_override()
+    {
+        let font = load_font();
+        let y_positions = |options: &LayoutOptions| font.layout("|\n|\n|", options).iter().map(|x| x.y).collect::<Vec<i32>>();
+
+        assert_eq!(vec![3, 85, 167], y_positions(&LayoutOptions::default()));
+        assert_eq!(vec![3, 63, 123], y_positions(&LayoutOptions{ line_height: Some(60), ..LayoutOptions::default() }));
+    }
+
+    #[test]
+    fn check_baseline_anchor()
+    {
+        let font = load_font();
+        assert_eq!(53, font.baseline_offset());
+
+        // The base is 53, so the bar with a y offset of 3 starts 50 pixels above the baseline
+        let baseline = LayoutOptions{ anchor: VerticalAnchor::Baseline, ..LayoutOptions::default() };
+        let y_positions = font.layout("|\n|", &baseline).iter().map(|x| x.y).collect::<Vec<i32>>();
+        assert_eq!(vec![-50, 32], y_positions);
+    }
+
+    #[test]
+    fn check_line_metrics()
+    {
+        let font = load_font();
+
+        // The line height is 82 and the base is 53
+        assert_eq!(82, font.line_height());
+        assert_eq!(53, font.ascent());
+        assert_eq!(29, font.descent());
+
+        let font = parse_str("char id=65 x=0 y=0 width=10 height=10", AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!((0, 0, 0), (font.line_height(), font.ascent(), font.descent()));
+    }
+
+    #[test]
+    fn check_layout_into()
+    {
+        let font = load_font();
+        let mut positioned_glyphs = Vec::new();
+
+        font.layout_into("| | |", &LayoutOptions::default(), &mut positioned_glyphs);
+        assert_eq!(5, positioned_glyphs.len());
+        let capacity = positioned_glyphs.capacity();
+
+        // The buffer is cleared rather than added to, and keeps its allocation
+        font.layout_into("||", &LayoutOptions::default(), &mut positioned_glyphs);
+        assert_eq!(font.layout("||", &LayoutOptions::default()), positioned_glyphs);
+        assert_eq!(capacity, positioned_glyphs.capacity());
+
+        // The positioned characters refer to the characters of the font
+        assert!(std::ptr::eq(&font.characters[0], positioned_glyphs[0].glyph));
+    }
+
+    #[test]
+    fn check_layout_ndc()
+    {
+        let font = load_font();
+        let quads = font.layout_ndc("||", &LayoutOptions::default(), (1024, 512), (3, 0));
+
+        // The bar is 22 by 72 pixels with an offset of (-3, 3), so the first one starts at the left
+        // of the screen and 3 pixels down
+        assert_eq!(2, quads.len());
+        assert_eq!((-1.0, 1.0 - 6.0 / 512.0), quads[0].positions[TOP_LEFT_INDEX]);
+        assert_eq!((44.0 / 1024.0 - 1.0, 1.0 - 6.0 / 512.0), quads[0].positions[TOP_RIGHT_INDEX]);
+        assert_eq!((-1.0, 1.0 - 150.0 / 512.0), quads[0].positions[BOTTOM_LEFT_INDEX]);
+        assert_eq!((44.0 / 1024.0 - 1.0, 1.0 - 150.0 / 512.0), quads[0].positions[BOTTOM_RIGHT_INDEX]);
+        assert_eq!(font.characters[0].texture_coordinates, quads[0].texture_coordinates);
+
+        // The second bar is one advance of 30 pixels to the right
+        assert_eq!((60.0 / 1024.0 - 1.0, 1.0 - 6.0 / 512.0), quads[1].positions[TOP_LEFT_INDEX]);
+
+        assert!(font.layout_ndc("||", &LayoutOptions::default(), (0, 512), (0, 0)).is_empty());
+
+        // Even on a screen with an odd size, each corner converts back to a whole pixel
+        let scaled = font.scaled(1.5);
+        let quads = scaled.layout_ndc("| |", &LayoutOptions::default(), (1023, 511), (7, 5));
+        for (x, y) in quads.iter().flat_map(|x| x.positions)
+        {
+            let pixel = ((x + 1.0) / 2.0 * 1023.0, (1.0 - y) / 2.0 * 511.0);
+            assert!((pixel.0 - pixel.0.round()).abs() < 1e-3 && (pixel.1 - pixel.1.round()).abs() < 1e-3, "{:?}", pixel);
+        }
+    }
+
+    #[test]
+    fn check_snap_to_pixel()
+    {
+        let contents = "common lineHeight=20 base=16\n\
+                        char id=65 x=0 y=0 width=10 height=10 xoffset=0.25 yoffset=1.5 xadvance=10.5\n";
+        let options = ParseOptions{ fractional_values: true, ..ParseOptions::default() };
+        let font = parse_reader_with_options(contents.as_bytes(), AtlasDimensions{ width: 512, height: 512 }, &options).unwrap();
+        let unsnapped = LayoutOptions{ snap_to_pixel: false, ..LayoutOptions::default() };
+
+        // Snapped, each advance is rounded to 11 pixels before it is added up
+        let snapped_glyphs = font.layout("AAAA", &LayoutOptions::default());
+        assert_eq!(vec![0, 11, 22, 33], snapped_glyphs.iter().map(|x| x.x).collect::<Vec<i32>>());
+        assert_eq!(vec![0.0, 11.0, 22.0, 33.0], snapped_glyphs.iter().map(|x| x.x_f32).collect::<Vec<f32>>());
+        assert_eq!(2.0, snapped_glyphs[0].y_f32);
+        assert_eq!(44, font.block_bounds_with("AAAA", &LayoutOptions::default()).0);
+
+        // Otherwise the fractions are kept, and the whole pixel positions are rounded from them
+        let unsnapped_glyphs = font.layout("AAAA", &unsnapped);
+        assert_eq!(vec![0.25, 10.75, 21.25, 31.75], unsnapped_glyphs.iter().map(|x| x.x_f32).collect::<Vec<f32>>());
+        assert_eq!(vec![0, 11, 21, 32], unsnapped_glyphs.iter().map(|x| x.x).collect::<Vec<i32>>());
+        assert_eq!(1.5, unsnapped_glyphs[0].y_f32);
+        assert_eq!(42, font.block_bounds_with("AAAA", &unsnapped).0);
+
+        let quads = font.layout_ndc("AA", &unsnapped, (100, 100), (0, 0));
+        assert_eq!(2.0 * 10.75 / 100.0 - 1.0, quads[1].positions[TOP_LEFT_INDEX].0);
+    }
+
+    #[test]
+    fn check_shape()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // The space followed by the bar is kerned by -1, the bar followed by the space by 2
+        let shaped = font.shape("| |").map(|x| (x.glyph.id, x.advance)).collect::<Vec<(Option<i32>, i32)>>();
+        assert_eq!(vec![(Some(124), 32), (Some(32), 31), (Some(124), 30)], shaped);
+
+        // The font does not have A, so the space is not kerned with the bar after it
+        let shaped = font.shape("| A|").map(|x| (x.glyph.id, x.advance)).collect::<Vec<(Option<i32>, i32)>>();
+        assert_eq!(vec![(Some(124), 32), (Some(32), 32), (Some(124), 30)], shaped);
+        assert_eq!(vec![-3, 32, 61], x_positions(&font, "| A|", &LayoutOptions::default()));
+
+        assert_eq!(0, font.shape("").count());
+    }
+
+    #[test]
+    fn check_right_to_left()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        let right_to_left = LayoutOptions{ direction: Direction::RightToLeft, ..LayoutOptions::default() };
+
+        // The bar has an advance of 30 and an x offset of -3, the space an advance of 32. The bar
+        // followed by the space is kerned by 2, the space followed by the bar by -1
+        assert_eq!(vec![-3, 32, 60], x_positions(&font, "| |", &LayoutOptions::default()));
+        assert_eq!(vec![-33, -64, -96], x_positions(&font, "| |", &right_to_left));
+
+        // Each character covers the same part of the line as it does left to right, mirrored
+        // around the start of the text
+        let pen_positions = |options: &LayoutOptions| font.layout("| |", options).iter()
+            .map(|x| (x.x - x.glyph.x_offset.unwrap(), x.glyph.x_advance.unwrap())).collect::<Vec<(i32, i32)>>();
+        for ((left_to_right, advance), (right_to_left, _)) in pen_positions(&LayoutOptions::default()).into_iter().zip(pen_positions(&right_to_left))
+        {
+            assert_eq!(-(left_to_right + advance), right_to_left);
+        }
+
+        // Tabs move to the next tab stop to the left, four spaces (128 pixels) from the start
+        assert_eq!(vec![-33, -161], x_positions(&font, "|\t|", &right_to_left));
+    }
+
+    #[test]
+    fn check_collapse_whitespace()
+    {
+        let font = load_font();
+        let collapse = LayoutOptions{ whitespace: Whitespace::Collapse, ..LayoutOptions::default() };
+
+        // Each space is positioned and advances by 32 pixels when preserved
+        assert_eq!(vec![-3, 30, 62, 94, 123], x_positions(&font, "|   |", &LayoutOptions::default()));
+
+        // A run of spaces and tabs is a single space, but separate runs are not joined
+        assert_eq!(vec![-3, 30, 59], x_positions(&font, "|   |", &collapse));
+        assert_eq!(vec![-3, 30, 59, 92, 121], x_positions(&font, "| \t |\t|", &collapse));
+
+        // Runs do not continue onto the next line
+        assert_eq!(vec![-3, 30, 0, 29], x_positions(&font, "| \n |", &collapse));
+    }
+
+    #[test]
+    fn check_tab_stops()
+    {
+        let font = load_font();
+
+        // The default tab stops are four spaces (128 pixels) apart
+        assert_eq!(vec![-3, 125], x_positions(&font, "|\t|", &LayoutOptions::default()));
+        assert_eq!(vec![125], x_positions(&font, "\t|", &LayoutOptions::default()));
+        assert_eq!(vec![253], x_positions(&font, "\t\t|", &LayoutOptions::default()));
+        assert_eq!(vec![-3, 27, 57, 87, 125], x_positions(&font, "||||\t|", &LayoutOptions::default()));
+
+        let pixel_tabs = LayoutOptions{ tab_width: TabWidth::Pixels(50), ..LayoutOptions::default() };
+        assert_eq!(vec![-3, 47], x_positions(&font, "|\t|", &pixel_tabs));
+
+        let space_tabs = LayoutOptions{ tab_width: TabWidth::Spaces(2), ..LayoutOptions::default() };
+        assert_eq!(vec![-3, 61], x_positions(&font, "|\t|", &space_tabs));
+    }
+
+    #[test]
+    fn check_text_bounds()
+    {
+        let font = load_font();
+
+        assert_eq!(92, font.measure_width("| |"));
+        assert_eq!((92, 82), font.text_bounds("| |"));
+
+        // The widest line determines the width, each line adds the line height of 82
+        assert_eq!((92, 246), font.text_bounds("|\n| |\n"));
+        assert_eq!((0, 0), font.text_bounds(""));
+
+        // Tabs are included in the width
+        assert_eq!((158, 82), font.text_bounds("|\t|"));
+    }
+
+    #[test]
+    fn check_block_bounds()
+    {
+        let font = load_font();
+
+        // Without any options the block is the same as the text bounds
+        assert_eq!((92, 246), font.block_bounds("|\n| |\n"));
+        assert_eq!(font.block_bounds("|\n| |\n"), font.block_bounds_with("|\n| |\n", &LayoutOptions::default()));
+        assert_eq!((0, 0), font.block_bounds(""));
+
+        let options = LayoutOptions{ line_height: Some(60), whitespace: Whitespace::Collapse, ..LayoutOptions::default() };
+        assert_eq!((92, 180), font.block_bounds_with("|\n|   |\n", &options));
+
+        let right_to_left = LayoutOptions{ direction: Direction::RightToLeft, ..LayoutOptions::default() };
+        assert_eq!((92, 82), font.block_bounds_with("| |", &right_to_left));
+    }
+
+    #[test]
+    fn check_alignment()
+    {
+        let font = load_font();
+        let text = "|\n| |\n||";
+        let aligned = |alignment, direction| LayoutOptions{ alignment: Some(alignment), direction, ..LayoutOptions::default() };
+
+        // The lines are 30, 92 and 60 pixels wide, and the bar has an x offset of -3
+        assert_eq!(x_positions(&font, text, &LayoutOptions::default()), x_positions(&font, text, &aligned(Alignment::Left, Direction::LeftToRight)));
+        assert_eq!(vec![28, -3, 30, 59, 13, 43], x_positions(&font, text, &aligned(Alignment::Center, Direction::LeftToRight)));
+
+        // The left and right of the pen positions each line covers, in the order of the lines
+        let line_extents = |options: &LayoutOptions|
+            {
+                let mut extents: Vec<(i32, i32, i32)> = Vec::new();
+                for positioned in font.layout(text, options)
+                {
+                    let left = positioned.x - positioned.glyph.x_offset.unwrap();
+                    let right = left + positioned.glyph.x_advance.unwrap();
+                    let pen_y = positioned.y - positioned.glyph.y_offset.unwrap();
+                    match extents.last_mut()
+                    {
+                        Some((y, line_left, line_right)) if *y == pen_y => { *line_left = left.min(*line_left); *line_right = right.max(*line_right); },
+                        _ => extents.push((pen_y, left, right))
+                    }
+                }
+                extents
+            };
+
+        // Right aligned lines end at the same position, the right of the widest line, and left
+        // aligned lines start at the same position
+        for direction in [Direction::LeftToRight, Direction::RightToLeft]
+        {
+            let line_ends = line_extents(&aligned(Alignment::Right, direction)).iter().map(|x| x.2).collect::<Vec<i32>>();
+            assert!(line_ends.iter().all(|x| *x == line_ends[0]), "{:?}", line_ends);
+
+            let line_starts = line_extents(&aligned(Alignment::Left, direction)).iter().map(|x| x.1).collect::<Vec<i32>>();
+            assert!(line_starts.iter().all(|x| *x == line_starts[0]), "{:?}", line_starts);
+        }
+
+        // Without an alignment, lines start at the start of the text, so right to left lines are
+        // already aligned right
+        let right_to_left = aligned(Alignment::Right, Direction::RightToLeft);
+        assert_eq!(x_positions(&font, text, &LayoutOptions{ direction: Direction::RightToLeft, ..LayoutOptions::default() }), x_positions(&font, text, &right_to_left));
+    }
+
+    #[test]
+    fn check_center_offset()
+    {
+        let font = load_font();
+
+        // The text is 92 pixels wide and, with two lines, 164 pixels tall
+        assert_eq!(54, font.center_offset("| |", 200));
+        assert_eq!(54, font.center_offset("| |\n|", 200));
+        assert_eq!(18, font.vertical_center_offset("| |\n|", 200));
+
+        // Text larger than the box starts before it
+        assert_eq!(-21, font.center_offset("| |", 50));
+        assert_eq!(25, font.vertical_center_offset("", 50));
+    }
+}
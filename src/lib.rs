@@ -1,14 +1,19 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fmt::Debug;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fs;
+use std::io::BufRead;
+use std::num::IntErrorKind;
 use std::path::Path;
+use float_cmp::approx_eq;
 
-/// Helps to set all of the variables associated with a CharacterInformation struct
-macro_rules! set_char_values
+/// Helps to set all of the numeric variables associated with a struct read from a font line, such
+/// as a CharacterInformation struct
+macro_rules! set_numeric_values
 {
-    ($char_info: expr, $line: expr, $($member: tt, $member_expr: expr),*) =>
+    ($struct_info: expr, $line: expr, $($member: tt, $member_expr: expr),*) =>
     {
-        if let Some((identifier, value)) = extract_numeric_value($line)
+        if let Some((identifier, value)) = $crate::extract_numeric_value($line)
         {
             // When comparing the member value, the input to the macro must be an expression.
             // However, using an expression when assigning a member variable does not work.
@@ -17,75 +22,719 @@ macro_rules! set_char_values
             $(
                 if identifier == $member_expr
                 {
-                    $char_info.$member = Some(value);
+                    $struct_info.$member = Some(value);
                 }
             )+
         }
     };
 }
 
+#[cfg(feature = "zip")]
+mod archive;
+#[cfg(feature = "image")]
+mod atlas;
+#[cfg(feature = "async")]
+mod async_io;
+mod binary;
+mod buffer;
+mod coords;
+mod diff;
+mod error;
+/// Access to the parser from other languages, such as C
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod font;
+mod fractional;
+mod layout;
+mod measure_cache;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod options;
+mod outcome;
+mod packed;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod progress;
+/// The types and parse functions used by most programs, so that they can all be imported with
+/// `use angel_font_file_parser::prelude::*`
+pub mod prelude;
+/// Helpers for the tests of programs that use this library
+#[cfg(feature = "testing")]
+pub mod testing;
+mod validation;
+mod writer;
+mod xml;
+
+#[cfg(feature = "zip")]
+pub use archive::{parse_from_zip, read_page_from_zip};
+#[cfg(feature = "image")]
+pub use atlas::parse_file_with_page_dimensions;
+#[cfg(feature = "async")]
+pub use async_io::parse_async;
+pub use binary::parse_binary;
+pub use buffer::uv_buffer;
+pub use coords::{PixelPos, Uv};
+pub use diff::FontDiff;
+pub use error::{MissingField, ParseError};
+pub use font::{CharConflict, CommonInfo, DistanceFieldInfo, Font, FontInfo, KerningPair, PageInfo, ParseStats};
+pub use fractional::FractionalMetrics;
+pub use layout::{Alignment, Direction, LayoutOptions, NdcQuad, PositionedGlyph, ShapedGlyph, TabWidth, VerticalAnchor, Whitespace};
+pub use measure_cache::MeasureCache;
+#[cfg(feature = "metrics")]
+pub use metrics::GlyphMetrics;
+pub use options::{DuplicateChars, ParseOptions, RectEdges};
+pub use outcome::{parse_file_with_outcome, parse_reader_with_outcome, ParseOutcome, ParseWarning};
+pub use packed::PackedCharacterInfo;
+#[cfg(feature = "rayon")]
+pub use parallel::parse_parallel;
+pub use progress::parse_file_with_progress;
+pub use validation::GlyphOverlap;
+pub use xml::parse_xml;
+use binary::read_binary;
+use fractional::fill_in_fractional;
+use xml::{is_xml, read_xml};
+
 /// Extracts the required information to query the associated texture atlas [of the passed in font file]
-/// as well as render those characters onto a screen
+/// as well as render those characters onto a screen. The characters are always in the same order
+/// as their lines in the file
+///
+/// `file_location` - the location of the file in the angel file format
+pub fn extract_characters<A: AsRef<Path> + Debug + Clone>(file_location: A, atlas_dimensions: AtlasDimensions) -> Result<Vec<CharacterInfo>, ParseError>
+{
+    parse_file(file_location, atlas_dimensions).map(|font| font.characters)
+}
+
+/// Reads all of the information of the passed in font file that is supported by this library
+///
+/// `file_location` - the location of the file in the angel file format
+pub fn parse_file<A: AsRef<Path> + Debug + Clone>(file_location: A, atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    parse_file_with_options(file_location, atlas_dimensions, &ParseOptions::default())
+}
+
+/// Reads all of the information of the passed in font file that is supported by this library,
+/// handling unexpectedly formatted parts of the file as specified by the options
+///
+/// Binary font files store every value as a number of a fixed size, so only the rect edges,
+/// duplicate chars and strict options apply to them; the other options are for values that are
+/// written as text
 ///
 /// `file_location` - the location of the file in the angel file format
-pub fn extract_characters<A: AsRef<Path> + Debug + Clone>(file_location: A, atlas_dimensions: AtlasDimensions) -> Result<Vec<CharacterInfo>, String>
+/// `options` - how to handle the parts of the file that do not follow the expected format
+pub fn parse_file_with_options<A: AsRef<Path> + Debug + Clone>(file_location: A, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<Font, ParseError>
+{
+    // Problems with individual lines are only of interest in strict mode, which stops at the first one
+    decode_and_parse(&read_file(file_location)?, atlas_dimensions, options, &mut Vec::new())
+}
+
+/// Reads as much of the passed in font file as possible, rather than stopping at the first
+/// parameter that could not be read. Every such parameter is reported along with the information
+/// that could be read. Only problems that prevent the file from being read at all are returned as
+/// an error. Binary font files have no parameters that cannot be read, and so are never reported
+/// with any problems
+///
+/// `file_location` - the location of the file in the angel file format
+/// `options` - how to handle the parts of the file that do not follow the expected format. The
+///             strict option is ignored, as otherwise only the first problem could be reported
+pub fn parse_file_with_diagnostics<A: AsRef<Path> + Debug + Clone>(file_location: A, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<(Font, Vec<ParseError>), ParseError>
+{
+    let mut line_errors = Vec::new();
+    let font = decode_and_parse(&read_file(file_location)?, atlas_dimensions, &without_strict(options), &mut line_errors)?;
+    Ok((font, line_errors))
+}
+
+/// Reads all of the information supported by this library from the raw contents of a font file.
+/// Both the text and binary versions of the angel file format are supported; text can be encoded
+/// as UTF-8 (with or without a byte order mark), UTF-16 with a byte order mark, or as single byte
+/// characters for fonts using an ANSI character set
+///
+/// `data` - the complete contents of the font file
+pub fn parse_bytes(data: &[u8], atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    parse_bytes_with_options(data, atlas_dimensions, &ParseOptions::default())
+}
+
+/// Reads all of the information supported by this library from the raw contents of a font file,
+/// handling unexpectedly formatted parts of the file as specified by the options. Only the rect edges,
+/// duplicate chars and strict options apply to binary fonts, as in `parse_file_with_options`
+///
+/// `data` - the complete contents of the font file
+/// `options` - how to handle the parts of the file that do not follow the expected format
+pub fn parse_bytes_with_options(data: &[u8], atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<Font, ParseError>
+{
+    decode_and_parse(data, atlas_dimensions, options, &mut Vec::new())
+}
+
+/// Reads all of the information supported by this library from the contents of a font file in the
+/// text version of the angel file format. The contents can come from include_str!, so that a font
+/// shipped inside the executable is parsed once at startup:
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use angel_font_file_parser::prelude::*;
+///
+/// static FONT: OnceLock<Font> = OnceLock::new();
+///
+/// fn font() -> &'static Font
+/// {
+///     FONT.get_or_init(|| parse_str(include_str!("../test_files/validFormat.fnt"), AtlasDimensions{ width: 512, height: 512 }).unwrap())
+/// }
+///
+/// assert_eq!(Some(82), font().common.line_height);
+/// ```
+///
+/// `contents` - the complete contents of the font file
+pub fn parse_str(contents: &str, atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    parse_reader(contents.as_bytes(), atlas_dimensions)
+}
+
+/// Reads all of the information supported by this library from the given source of a font in the
+/// angel file format
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+pub fn parse_reader<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    parse_reader_with_options(reader, atlas_dimensions, &ParseOptions::default())
+}
+
+/// Reads all of the information supported by this library from the given source of a font in the
+/// angel file format, handling unexpectedly formatted parts of the font as specified by the options
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+/// `options` - how to handle the parts of the font that do not follow the expected format
+pub fn parse_reader_with_options<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<Font, ParseError>
+{
+    read_font(reader, atlas_dimensions, options, &mut Vec::new())
+}
+
+/// Reads as much of the given source of a font as possible, rather than stopping at the first
+/// parameter that could not be read. See `parse_file_with_diagnostics`
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+/// `options` - how to handle the parts of the font that do not follow the expected format. The
+///             strict option is ignored
+pub fn parse_reader_with_diagnostics<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<(Font, Vec<ParseError>), ParseError>
+{
+    let mut line_errors = Vec::new();
+    let font = read_font(reader, atlas_dimensions, &without_strict(options), &mut line_errors)?;
+    Ok((font, line_errors))
+}
+
+/// Reads the characters of the given source of a font in the text version of the angel file format
+/// into the given vector, the same as extract_characters. The vector is cleared first, and its
+/// allocation is reused, so that fonts that are read again every time they change, such as while
+/// editing them, do not allocate a new vector each time. If the font cannot be read, the vector is
+/// left empty, but keeps its allocation
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+/// `characters` - where the characters are placed, in the same order as their lines
+pub fn parse_into<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions, characters: &mut Vec<CharacterInfo>) -> Result<(), ParseError>
+{
+    characters.clear();
+    let mut font = Font::new(atlas_dimensions, RectEdges::HalfOpen);
+    font.characters = std::mem::take(characters);
+
+    let result = read_font_into(reader, atlas_dimensions, &ParseOptions::default(), &mut Vec::new(), &mut font);
+    *characters = font.characters;
+
+    if result.is_err()
+    {
+        characters.clear();
+    }
+
+    result
+}
+
+/// Finds the character with the given id in the given source of a font in the text version of the
+/// angel file format, stopping as soon as its char line is read rather than reading the whole font.
+/// If the font lists the character more than once, the first listing is used. Only the lines up
+/// to the character are checked, so problems later in the font are not found. As the lines are
+/// read as they come, rather than decoding the whole file first, only fonts in UTF-8 text are
+/// supported; XML, binary and other encodings are not recognized as fonts, and can instead be
+/// read with parse_bytes and searched with Font::glyph
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+/// `id` - the id of the character to find
+pub fn find_character<R: BufRead>(reader: R, id: i32, atlas_dimensions: AtlasDimensions) -> Result<Option<CharacterInfo>, ParseError>
+{
+    find_character_with_options(reader, id, atlas_dimensions, &ParseOptions::default())
+}
+
+/// Finds the character with the given id in the given source of a font, the same as
+/// find_character, handling unexpectedly formatted parts of the char lines as specified by the
+/// options. In strict mode, only the parameters of the character that is found are checked
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+/// `id` - the id of the character to find
+/// `options` - how to handle the parts of the font that do not follow the expected format
+pub fn find_character_with_options<R: BufRead>(reader: R, id: i32, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<Option<CharacterInfo>, ParseError>
+{
+    validate_atlas_dimensions(atlas_dimensions)?;
+
+    let mut found_recognized_line = false;
+    // Parameters with other names have to be read before the id of the line is known
+    let reads_only_id = options.field_aliases.is_empty() && !options.case_insensitive_names && !options.lenient_numbers;
+
+    for (index, read_line) in reader.lines().enumerate()
+    {
+        let line_number = index + 1;
+        let line = read_line.map_err(|err| ParseError::LineRead { line_number, error: err })?;
+
+        let trimmed_line = line.trim_start();
+        if trimmed_line.is_empty() || trimmed_line.starts_with('#')
+        {
+            continue;
+        }
+
+        let is_recognized = is_recognized_line(&line);
+        found_recognized_line |= is_recognized || options.assume_all_lines_are_chars;
+
+        // Lines that are assumed to be characters are only those that are not any other type of line
+        let is_char_line = line.starts_with("char id") || (options.assume_all_lines_are_chars && !is_recognized);
+        if !is_char_line
+        {
+            continue;
+        }
+
+        // Only the id is read until the character is found, as the rest of the line is not needed
+        if reads_only_id && line.starts_with("char id") && line.split_whitespace().nth(1).and_then(extract_numeric_value) != Some(("id".to_string(), id))
+        {
+            continue;
+        }
+
+        let line = if options.lenient_numbers { strip_number_suffixes(&line) } else { line };
+        let mut invalid_parameters = Vec::new();
+        let char_info = char_info_from_line(&line, atlas_dimensions, options, &mut invalid_parameters);
+
+        if char_info.id != Some(id)
+        {
+            continue;
+        }
+
+        if let (true, Some(parameter)) = (options.strict, invalid_parameters.into_iter().next())
+        {
+            return Err(line_error(parameter, line_number));
+        }
+
+        return Ok(Some(char_info));
+    }
+
+    // The same as when reading the whole font, a file without any of the expected lines is not a font
+    if !found_recognized_line
+    {
+        return Err(ParseError::NotABmFont);
+    }
+
+    Ok(None)
+}
+
+/// Copies the options with strict mode turned off, as strict mode stops at the first problem instead of
+/// reporting all of them
+fn without_strict(options: &ParseOptions) -> ParseOptions
+{
+    let mut options = options.clone();
+    options.strict = false;
+    options
+}
+
+/// Reads the complete contents of the given file
+fn read_file<A: AsRef<Path> + Debug + Clone>(file_location: A) -> Result<Vec<u8>, ParseError>
 {
     // Attempting to open the file specified by file location consumes the file location variable.
     // This is an issue when creating the error message if file opening failed
     let file_location_copy = file_location.clone();
 
-    let file = match File::open(file_location)
+    match fs::read(file_location)
     {
-        Ok(i) => i,
+        Ok(i) => Ok(i),
         Err(err) =>
             {
                 // The default error message, err, is not that great- does not provide the location of the file that could not be opened
-                return Err(format!("Unable to open file {:?}, with the error: {}", file_location_copy, err));
+                Err(ParseError::FileOpen { file_location: format!("{:?}", file_location_copy), error: err })
             }
-    };
+    }
+}
 
-    let reader = BufReader::new(file);
+/// Reads the contents of a font file in whichever format and text encoding it is stored in
+fn decode_and_parse(data: &[u8], atlas_dimensions: AtlasDimensions, options: &ParseOptions, line_errors: &mut Vec<ParseError>) -> Result<Font, ParseError>
+{
+    decode_and_parse_with(data, atlas_dimensions, options, line_errors, &mut PlainText)
+}
 
-    let mut characters = Vec::new();
+/// Changes how the contents of a font in the text version of the angel file format are read, such
+/// as to report how much of them has been read
+trait TextReaderAdapter
+{
+    /// Creates the reader the font lines are read from
+    ///
+    /// `contents` - the decoded text of the font file
+    fn wrap<'a>(&'a mut self, contents: &'a [u8]) -> impl BufRead + 'a;
+}
+
+/// Reads the contents of a font as they are
+struct PlainText;
+
+impl TextReaderAdapter for PlainText
+{
+    fn wrap<'a>(&'a mut self, contents: &'a [u8]) -> impl BufRead + 'a
+    {
+        contents
+    }
+}
+
+/// Reads the contents of a font file in whichever format and text encoding it is stored in, the
+/// same as decode_and_parse, reading the lines of text fonts through the given adapter
+///
+/// `adapter` - wraps the decoded contents of text fonts before they are read
+fn decode_and_parse_with<T: TextReaderAdapter>(data: &[u8], atlas_dimensions: AtlasDimensions, options: &ParseOptions, line_errors: &mut Vec<ParseError>, adapter: &mut T) -> Result<Font, ParseError>
+{
+    if data.starts_with(b"BMF")
+    {
+        return read_binary(data, atlas_dimensions, options);
+    }
+
+    let contents = decode_text(data)?;
+
+    if is_xml(&contents)
+    {
+        return read_xml(&contents, atlas_dimensions, options, line_errors);
+    }
+
+    read_font(adapter.wrap(contents.as_bytes()), atlas_dimensions, options, line_errors)
+}
+
+/// Reads a font in the text version of the angel file format. Parameters that could not be read
+/// stop the parsing in strict mode, and are otherwise added to the line errors
+fn read_font<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions, options: &ParseOptions, line_errors: &mut Vec<ParseError>) -> Result<Font, ParseError>
+{
+    let mut font = Font::new(atlas_dimensions, options.rect_edges);
+    read_font_into(reader, atlas_dimensions, options, line_errors, &mut font)?;
+    Ok(font)
+}
+
+/// Reads a font in the text version of the angel file format, the same as read_font, into the
+/// given font so that the allocations of its characters are reused. If the font cannot be read,
+/// the given font is left with the information read up to the problem
+///
+/// `font` - where the font is read into, which does not have any information yet
+fn read_font_into<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions, options: &ParseOptions, line_errors: &mut Vec<ParseError>, font: &mut Font) -> Result<(), ParseError>
+{
+    validate_atlas_dimensions(atlas_dimensions)?;
+
+    let mut found_recognized_line = false;
+    // The index of the first character with each id, used to merge duplicated characters
+    let mut char_indices = HashMap::new();
 
     for (index, read_line) in reader.lines().enumerate()
     {
+        // Line numbers are reported starting from 1, the same as text editors
+        let line_number = index + 1;
+
         let line = match read_line
         {
             Ok(i) => i,
             Err(err) =>
                 {
                     // Custom error message to have more information than the default err information
-                    return Err(format!("Unable to read line number {} with error: {}", index, err));
+                    return Err(ParseError::LineRead { line_number, error: err });
                 }
         };
 
-        // Only interested in file containing character information, not background information such
-        // as number of characters, name of the font, etc
-        if !line.starts_with("char id")
+        // Blank lines and comments are allowed in hand edited files, and are never part of the font
+        // even if the comment looks like a font line
+        let trimmed_line = line.trim_start();
+        if trimmed_line.is_empty() || trimmed_line.starts_with('#')
+        {
+            continue;
+        }
+
+        let line = if options.lenient_numbers { strip_number_suffixes(&line) } else { line };
+
+        let is_recognized = is_recognized_line(&line);
+
+        if is_recognized || options.assume_all_lines_are_chars
+        {
+            found_recognized_line = true;
+        }
+        else
+        {
+            font.unknown_lines.push(line.clone());
+            font.stats.skipped += 1;
+        }
+
+        let mut invalid_parameters = Vec::new();
+
+        // Lines that are assumed to be characters are only those that are not any other type of line
+        if line.starts_with("char id") || (options.assume_all_lines_are_chars && !is_recognized)
+        {
+            let char_info = char_info_from_line(&line, atlas_dimensions, options, &mut invalid_parameters);
+            font.stats.chars += 1;
+            add_character(font, char_info, atlas_dimensions, options, &mut char_indices);
+        }
+        else
+        {
+            // Lines such as the number of characters only describe the lines that follow them, and so
+            // do not have to be stored
+            match line.split_whitespace().next()
+            {
+                Some("info") =>
+                    {
+                        // A distanceField line that comes before the info line still belongs to the font
+                        let distance_range = font.info.distance_range;
+                        font.info = FontInfo::from_line(&line, &mut invalid_parameters);
+                        font.info.distance_range = font.info.distance_range.or(distance_range);
+                        font.stats.info_found = true;
+                    },
+                Some("common") =>
+                    {
+                        font.common = CommonInfo::from_line(&line, &mut invalid_parameters);
+                        font.stats.common_found = true;
+                    },
+                Some("distanceField") =>
+                    {
+                        font.info.read_distance_field_line(&line, &mut invalid_parameters);
+                        font.distance_field = DistanceFieldInfo::from_line(&line);
+                    },
+                Some("page") => font.pages.extend(PageInfo::from_line(&line, &mut invalid_parameters)),
+                Some("kerning") =>
+                    {
+                        if let Some(kerning) = KerningPair::from_line(&line, &mut invalid_parameters)
+                        {
+                            font.add_kerning(kerning);
+                            font.stats.kernings += 1;
+                        }
+                    },
+                _ => {}
+            }
+        }
+
+        if !invalid_parameters.is_empty()
+        {
+            font.stats.errored += 1;
+        }
+
+        for parameter in invalid_parameters
+        {
+            let error = line_error(parameter, line_number);
+
+            if options.strict
+            {
+                return Err(error);
+            }
+
+            line_errors.push(error);
+        }
+    }
+
+    // A file without any of the expected lines is not valid but empty- it is not a font file at all
+    if !found_recognized_line
+    {
+        return Err(ParseError::NotABmFont);
+    }
+
+    if options.strict
+    {
+        font.validate_pages()?;
+    }
+
+    font.rebuild_glyph_ids();
+    Ok(())
+}
+
+/// Creates the error for a parameter of a line that could not be read
+///
+/// `parameter` - the parameter that could not be read
+/// `line_number` - the line number of the line the parameter is on, starting from 1
+fn line_error(parameter: String, line_number: usize) -> ParseError
+{
+    match overflowing_field(&parameter)
+    {
+        Some(field) => ParseError::ValueOverflow { field: field.to_string(), line: line_number },
+        None => ParseError::InvalidParameter { line_number, parameter },
+    }
+}
+
+/// Adds a character that was read to the font, or merges it into the first character with the same
+/// id if duplicated characters are merged
+///
+/// `char_info` - the character that was read
+/// `options` - the options the font is read with
+/// `char_indices` - the index of the first character with each id, which is updated as characters are added
+fn add_character(font: &mut Font, char_info: CharacterInfo, atlas_dimensions: AtlasDimensions, options: &ParseOptions, char_indices: &mut HashMap<i32, usize>)
+{
+    match (options.duplicate_chars, char_info.id.and_then(|x| char_indices.get(&x).copied()))
+    {
+        (DuplicateChars::Merge, Some(index)) =>
+            {
+                font.merge_character(index, &char_info);
+                // The merged values can complete the area of a character that did not have one
+                calculate_char_texture_coords(&mut font.characters[index], atlas_dimensions, options.rect_edges);
+            },
+        _ =>
+            {
+                if let Some(id) = char_info.id
+                {
+                    char_indices.entry(id).or_insert(font.characters.len());
+                }
+
+                font.characters.push(char_info);
+            }
+    }
+}
+
+/// Checks that the texture atlas has an area, as otherwise the texture coordinates would be
+/// infinite or NaN
+fn validate_atlas_dimensions(atlas_dimensions: AtlasDimensions) -> Result<(), ParseError>
+{
+    if atlas_dimensions.width <= 0 || atlas_dimensions.height <= 0
+    {
+        return Err(ParseError::InvalidAtlas { width: atlas_dimensions.width, height: atlas_dimensions.height });
+    }
+
+    Ok(())
+}
+
+/// Parses the given char line into the character it describes
+///
+/// `invalid_parameters` - where the parameters that could not be read are added to
+fn char_info_from_line(line: &str, atlas_dimensions: AtlasDimensions, options: &ParseOptions, invalid_parameters: &mut Vec<String>) -> CharacterInfo
+{
+    let mut char_info = CharacterInfo::new();
+
+    // The first split result is usually the line type, which is not a parameter. Lines that are
+    // only assumed to be char lines might not have a line type
+    let mut split_results = line.split_whitespace().peekable();
+    split_results.next_if(|x| !x.contains('='));
+
+    for split_result in split_results
+    {
+        let parameter = with_standard_name(split_result, options);
+
+        // Pages are numbered from zero, so a negative page cannot refer to any of them
+        if !matches!(extract_numeric_value(&parameter), Some((identifier, value)) if identifier != "page" || value >= 0)
         {
+            if options.fractional_values && fill_in_fractional(&mut char_info, &parameter)
+            {
+                continue;
+            }
+
+            invalid_parameters.push(split_result.to_string());
             continue;
         }
 
-        let mut char_info = CharacterInfo::new();
+        fill_in_char_info(&mut char_info, &parameter);
+    }
+
+    if options.missing_size_as_zero
+    {
+        // Only a size that is left out is zero; a size with an invalid value is still unknown
+        let has_parameter = |identifier: &str| line.split_whitespace().any(|x| with_standard_name(x, options).split('=').next() == Some(identifier));
 
-        for split_result in line.split_whitespace().filter(|x| x.contains('='))
+        if char_info.width.is_none() && !has_parameter("width")
         {
-            fill_in_char_info(&mut char_info, split_result);
-            calculate_char_texture_coords(&mut char_info, atlas_dimensions);
+            char_info.width = Some(0);
         }
 
-        characters.push(char_info);
+        if char_info.height.is_none() && !has_parameter("height")
+        {
+            char_info.height = Some(0);
+        }
     }
 
-    Ok(characters)
+    calculate_char_texture_coords(&mut char_info, atlas_dimensions, options.rect_edges);
+    char_info
+}
+
+/// Replaces the name of the parameter with the standard name it is an alias of, if it is one. If
+/// the options ignore the case of names, the name is also made lowercase like the standard names
+///
+/// `parameter` - the memberVariable-value string extracted from the char line
+/// `options` - the other names of parameters, and if their case is ignored
+fn with_standard_name<'a>(parameter: &'a str, options: &ParseOptions) -> Cow<'a, str>
+{
+    let (identifier, value) = match parameter.split_once('=')
+    {
+        Some(split) => split,
+        None => return Cow::Borrowed(parameter)
+    };
+
+    let alias = if options.case_insensitive_names
+    {
+        options.field_aliases.iter().find(|(alias, _)| alias.eq_ignore_ascii_case(identifier)).map(|(_, standard_name)| standard_name)
+    }
+    else
+    {
+        options.field_aliases.get(identifier)
+    };
+
+    match alias
+    {
+        Some(standard_name) => Cow::Owned(format!("{}={}", standard_name, value)),
+        None if options.case_insensitive_names && identifier.chars().any(|x| x.is_ascii_uppercase()) => Cow::Owned(format!("{}={}", identifier.to_ascii_lowercase(), value)),
+        None => Cow::Borrowed(parameter)
+    }
+}
+
+/// Converts the contents of a text font file into a string, based off of the byte order mark
+fn decode_text(data: &[u8]) -> Result<Cow<'_, str>, ParseError>
+{
+    if let Some(utf_16_data) = data.strip_prefix(&[0xFF, 0xFE])
+    {
+        return decode_utf_16(utf_16_data, u16::from_le_bytes);
+    }
+
+    if let Some(utf_16_data) = data.strip_prefix(&[0xFE, 0xFF])
+    {
+        return decode_utf_16(utf_16_data, u16::from_be_bytes);
+    }
+
+    let data = data.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(data);
+
+    match std::str::from_utf8(data)
+    {
+        Ok(contents) => Ok(Cow::Borrowed(contents)),
+        // Fonts using an ANSI character set store names, such as the face, as single bytes
+        Err(_) => Ok(Cow::Owned(data.iter().map(|x| *x as char).collect()))
+    }
+}
+
+/// Converts UTF-16 data, without its byte order mark, into a string
+///
+/// `to_code_unit` - converts two bytes into a code unit, based off of the byte order of the data
+fn decode_utf_16(data: &[u8], to_code_unit: fn([u8; 2]) -> u16) -> Result<Cow<'_, str>, ParseError>
+{
+    if !data.len().is_multiple_of(2)
+    {
+        return Err(ParseError::InvalidEncoding("UTF-16 data has an odd number of bytes".to_string()));
+    }
+
+    let code_units = data.chunks_exact(2).map(|x| to_code_unit([x[0], x[1]])).collect::<Vec<u16>>();
+
+    match String::from_utf16(&code_units)
+    {
+        Ok(contents) => Ok(Cow::Owned(contents)),
+        Err(err) => Err(ParseError::InvalidEncoding(err.to_string()))
+    }
+}
+
+/// Determines if the given line is one of the line types of the angel font format, regardless of
+/// whether the information on the line is used
+fn is_recognized_line(line: &str) -> bool
+{
+    if line.starts_with("char id")
+    {
+        return true;
+    }
+
+    matches!(line.split_whitespace().next(), Some("info" | "common" | "distanceField" | "page" | "chars" | "kernings" | "kerning"))
 }
 
 /// Parses the given line to obtain the character information used for rendering
 fn fill_in_char_info(char_info: &mut CharacterInfo, line: &str)
 {
     // All required information has the form of [memberName]=[value]
-    set_char_values!(char_info, line,
+    set_numeric_values!(char_info, line,
                 id, "id",
                 x, "x",
                 y, "y",
@@ -95,34 +744,68 @@ fn fill_in_char_info(char_info: &mut CharacterInfo, line: &str)
                 y_offset, "yoffset",
                 x_advance, "xadvance",
                 page, "page",
-                chnl, "chnl");
+                chnl, "chnl",
+                rot, "rot");
 }
 
 /// Finds the texture coordinates on the given atlas that contains the texture data for the given
 /// character. Only if the character has the required information to calculate the texture coordinates
 /// is anything computed
-fn calculate_char_texture_coords(char_info: &mut CharacterInfo, atlas_dimensions: AtlasDimensions)
+fn calculate_char_texture_coords(char_info: &mut CharacterInfo, atlas_dimensions: AtlasDimensions, rect_edges: RectEdges)
 {
     // Calculate the texture coordinates of the character- this is only done when it is possible
     // to find all four required texture coordinates to texture a plane
-    match (char_info.x, char_info.y, char_info.width, char_info.height)
+    if let (Some(x), Some(y), Some(width), Some(height)) = (char_info.x, char_info.y, char_info.width, char_info.height)
     {
-        (Some(x), Some(y), Some(width), Some(height)) =>
-            {
-                let top_left_coord_x = x as f32 / atlas_dimensions.width as f32;
-                // The bitmap y coordinates starts from the top, but OpenGL's texture coordinates
-                // start from the bottom, hence the subtraction
-                let top_left_coord_y = 1.0 - (y as f32 / atlas_dimensions.height as f32);
-                let length_coord_x = width as f32 / atlas_dimensions.width as f32;
-                let length_coord_y = height as f32 / atlas_dimensions.height as f32;
-
-                char_info.texture_coordinates[TOP_LEFT_INDEX] = (top_left_coord_x, top_left_coord_y);
-                char_info.texture_coordinates[TOP_RIGHT_INDEX] = (top_left_coord_x + length_coord_x, top_left_coord_y);
-                char_info.texture_coordinates[BOTTOM_LEFT_INDEX] = (top_left_coord_x, top_left_coord_y - length_coord_y);
-                char_info.texture_coordinates[BOTTOM_RIGHT_INDEX] = (top_left_coord_x + length_coord_x, top_left_coord_y - length_coord_y);
-            },
-        _ => {}
+        if !char_info.is_rotated()
+        {
+            char_info.texture_coordinates = rect_texture_coords(x, y, width, height, atlas_dimensions, rect_edges);
+            return;
+        }
+
+        // The area of a rotated character is as wide as the character is tall. Each corner of the
+        // upright character is at the next corner clockwise of that area, as the character was
+        // turned clockwise to fit into it
+        let area_coords = rect_texture_coords(x, y, height, width, atlas_dimensions, rect_edges);
+        let [top_left, top_right, bottom_left, bottom_right] = char_info.atlas_corners();
+        char_info.texture_coordinates[top_left] = area_coords[TOP_LEFT_INDEX];
+        char_info.texture_coordinates[top_right] = area_coords[TOP_RIGHT_INDEX];
+        char_info.texture_coordinates[bottom_left] = area_coords[BOTTOM_LEFT_INDEX];
+        char_info.texture_coordinates[bottom_right] = area_coords[BOTTOM_RIGHT_INDEX];
+    }
+}
+
+/// Finds the texture coordinates of the given area of the atlas, in pixels with y increasing downward
+fn rect_texture_coords(x: i32, y: i32, width: i32, height: i32, atlas_dimensions: AtlasDimensions, rect_edges: RectEdges) -> [Uv; 4]
+{
+    let mut texture_coordinates = [Uv(0.0, 0.0); 4];
+
+    // An atlas without an area would result in infinite or NaN coordinates, so the coordinates are
+    // left at zero
+    if atlas_dimensions.width <= 0 || atlas_dimensions.height <= 0
+    {
+        return texture_coordinates;
     }
+
+    let top_left_coord_x = x as f32 / atlas_dimensions.width as f32;
+    // The bitmap y coordinates starts from the top, but OpenGL's texture coordinates
+    // start from the bottom, hence the subtraction
+    let top_left_coord_y = 1.0 - (y as f32 / atlas_dimensions.height as f32);
+    // Inclusive areas end one pixel before the same half-open area would
+    let (width, height) = match rect_edges
+    {
+        RectEdges::HalfOpen => (width, height),
+        RectEdges::Inclusive => (width.saturating_sub(1).max(0), height.saturating_sub(1).max(0))
+    };
+    let length_coord_x = width as f32 / atlas_dimensions.width as f32;
+    let length_coord_y = height as f32 / atlas_dimensions.height as f32;
+
+    texture_coordinates[TOP_LEFT_INDEX] = Uv(top_left_coord_x, top_left_coord_y);
+    texture_coordinates[TOP_RIGHT_INDEX] = Uv(top_left_coord_x + length_coord_x, top_left_coord_y);
+    texture_coordinates[BOTTOM_LEFT_INDEX] = Uv(top_left_coord_x, top_left_coord_y - length_coord_y);
+    texture_coordinates[BOTTOM_RIGHT_INDEX] = Uv(top_left_coord_x + length_coord_x, top_left_coord_y - length_coord_y);
+
+    texture_coordinates
 }
 
 /// Extracts the given string into two outputs: the name of the variable related to the font and the
@@ -133,6 +816,12 @@ fn extract_numeric_value(input: &str) -> Option<(String, i32)>
 {
     let mut result = ("".to_string(), 0);
 
+    // A parameter without a value, such as xadvance=, has nothing that can be read
+    if input.ends_with('=')
+    {
+        return None;
+    }
+
     // Should only be two possible split results if input is of the form of [variable]=[value]
     if input.split('=').count() != 2
     {
@@ -159,15 +848,212 @@ fn extract_numeric_value(input: &str) -> Option<(String, i32)>
     Some(result)
 }
 
+/// Finds the name of the parameter if its value is a number that does not fit in an i32, such as
+/// width=99999999999, rather than a value that is not a number at all
+///
+/// `parameter` - a parameter of the form [variable]=[value]
+fn overflowing_field(parameter: &str) -> Option<&str>
+{
+    let (identifier, value) = parameter.split_once('=')?;
+
+    match value.parse::<i32>()
+    {
+        Err(err) if matches!(err.kind(), IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => Some(identifier),
+        _ => None
+    }
+}
+
+/// Splits the given line into all of its memberVariable-value pairs. Unlike the numeric values of a
+/// character line, values can be quoted strings that contain whitespace; the quotes are not part of
+/// the returned value
+///
+/// `line` - the line read from the font file
+/// `invalid_parameters` - where the parts of the line that are not of the form [variable]=[value]
+///                        are added to
+fn extract_parameters<'a>(line: &'a str, invalid_parameters: &mut Vec<String>) -> Vec<(&'a str, &'a str)>
+{
+    let mut parameters = Vec::new();
+    let mut token_start = None;
+    let mut in_quotes = false;
+    let mut is_line_type = true;
+    let mut after_backslash = false;
+
+    // A trailing space is chained so that the last token is completed inside the loop
+    for (index, character) in line.char_indices().chain(std::iter::once((line.len(), ' ')))
+    {
+        // A quote escaped with a backslash, such as in face="He said \"hi\"", is part of the value.
+        // An escaped backslash does not escape what follows it, so face="Foo\\" ends at its quote
+        let is_escaped = in_quotes && after_backslash;
+        if character == '"' && !is_escaped
+        {
+            in_quotes = !in_quotes;
+        }
+
+        after_backslash = character == '\\' && !is_escaped;
+
+        if character.is_whitespace() && !in_quotes
+        {
+            if let Some(start) = token_start.take()
+            {
+                match line[start..index].split_once('=')
+                {
+                    Some((identifier, value)) => parameters.push((identifier, unquote(value))),
+                    // The line type is the only part of the line that has no value
+                    None if is_line_type => {},
+                    None => invalid_parameters.push(line[start..index].to_string())
+                }
+
+                is_line_type = false;
+            }
+        }
+        else if token_start.is_none()
+        {
+            token_start = Some(index);
+        }
+    }
+
+    parameters
+}
+
+/// Removes the quotes around the value, if it has them. Only one quote is removed from each end,
+/// as the value can end with an escaped quote
+fn unquote(value: &str) -> &str
+{
+    let value = value.strip_prefix('"').unwrap_or(value);
+    value.strip_suffix('"').unwrap_or(value)
+}
+
+/// Replaces the escaped quotes and backslashes of a quoted value, `\"` and `\\`, with the
+/// characters they stand for. Other backslashes are kept, so that values written without escaping, such as
+/// the paths of some generators, are unchanged
+fn unescape(value: &str) -> String
+{
+    let mut unescaped = String::with_capacity(value.len());
+    let mut characters = value.chars().peekable();
+
+    while let Some(character) = characters.next()
+    {
+        match (character, characters.peek())
+        {
+            ('\\', Some('"' | '\\')) => unescaped.extend(characters.next()),
+            _ => unescaped.push(character)
+        }
+    }
+
+    unescaped
+}
+
+/// Escapes the quotes and backslashes of the value and puts it in quotes, so that it is read back
+/// as the same value
+fn quote(value: &str) -> String
+{
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Removes the characters that follow the number of each unquoted value of the line, such as the px
+/// of xadvance=32px, so that the values can be read as numbers
+///
+/// `line` - the line read from the font file
+fn strip_number_suffixes(line: &str) -> String
+{
+    let mut stripped = String::with_capacity(line.len());
+    let mut in_quotes = false;
+
+    for piece in line.split_inclusive(char::is_whitespace)
+    {
+        let token = piece.trim_end_matches(char::is_whitespace);
+
+        match token.split_once('=')
+        {
+            Some((identifier, value)) if !in_quotes && !value.starts_with('"') =>
+                {
+                    stripped.push_str(identifier);
+                    stripped.push('=');
+                    stripped.push_str(number_prefix(value));
+                },
+            _ => stripped.push_str(token)
+        }
+
+        stripped.push_str(&piece[token.len()..]);
+
+        // Quoted values can contain whitespace, and so span more than one piece
+        if !token.matches('"').count().is_multiple_of(2)
+        {
+            in_quotes = !in_quotes;
+        }
+    }
+
+    stripped
+}
+
+/// Finds the leading number of the value, which can also be a comma separated list of numbers.
+/// Values that do not start with a number are returned unchanged
+fn number_prefix(value: &str) -> &str
+{
+    let end = value.find(|x: char| !(x.is_ascii_digit() || x == '-' || x == ',')).unwrap_or(value.len());
+    let prefix = value[..end].trim_end_matches([',', '-']);
+
+    if prefix.contains(|x: char| x.is_ascii_digit())
+    {
+        prefix
+    }
+    else
+    {
+        value
+    }
+}
+
 // *** Required Structures ***
 
-#[derive(Copy, Clone)]
+/// The size, in pixels, of the texture atlas that contains the characters of the font
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct AtlasDimensions
 {
     pub width: i32,
     pub height: i32,
 }
 
+impl AtlasDimensions
+{
+    /// The ratio of the width of the atlas to its height.
+    ///
+    /// Texture coordinates are normalized separately along each axis, so a quad whose size is taken
+    /// from the texture coordinates of a character is stretched when the atlas is not square.
+    /// Multiplying the horizontal length of such a quad by this ratio removes the stretching. Sizing
+    /// the quad using the width and height of the character in pixels avoids the issue entirely
+    pub fn aspect_ratio(&self) -> f32
+    {
+        self.width as f32 / self.height as f32
+    }
+
+    /// Guesses the size of the texture atlas from the areas of the characters, for when neither the
+    /// size nor the common line of the font is known. The size is the furthest right and the
+    /// furthest down any character reaches, each rounded up to the next power of two as atlases
+    /// usually are. This is only approximate: the actual atlas can be larger than its characters
+    /// need. Without any characters with an area, the size is 1 by 1. The characters can be read
+    /// with any size and then given this one with Font::recalculate_texture_coords
+    ///
+    /// `characters` - the characters of the font
+    pub fn infer(characters: &[CharacterInfo]) -> AtlasDimensions
+    {
+        let (mut right, mut bottom) = (1, 1);
+
+        for char_info in characters
+        {
+            if let Ok((x, y, width, height)) = char_info.require_rect()
+            {
+                let (width, height) = if char_info.is_rotated() { (height, width) } else { (width, height) };
+                right = right.max(x.saturating_add(width));
+                bottom = bottom.max(y.saturating_add(height));
+            }
+        }
+
+        // Capped at the largest power of two that fits into an i32
+        let round_up = |extent: i32| (extent as u32).next_power_of_two().min(1 << 30) as i32;
+        AtlasDimensions { width: round_up(right), height: round_up(bottom) }
+    }
+}
+
 /// The indexes representing the corner of a plane that is used to texture a portion of the bitmap.
 /// Used in with the CharacterInfo texture_coordinates member variable
 const TOP_LEFT_INDEX: usize = 1;
@@ -177,7 +1063,7 @@ const BOTTOM_RIGHT_INDEX: usize = 3;
 
 /// Stores the information required to extract a character from the associated texture atlas [of the
 /// passed in font file] as well as render the character to a screen
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CharacterInfo
 {
     pub id: Option<i32>,
@@ -190,37 +1076,232 @@ pub struct CharacterInfo
     pub x_advance: Option<i32>,
     pub page: Option<i32>,
     pub chnl: Option<i32>,
-    pub texture_coordinates: [(f32, f32); 4],
+    /// Non-zero if the packer of the atlas rotated the character 90 degrees clockwise. The width
+    /// and height are still those of the upright character, so its area of the atlas is height
+    /// pixels wide and width pixels tall
+    pub rot: Option<i32>,
+    pub texture_coordinates: [Uv; 4],
+    /// The offsets and advance as they were written, if any of them had a fraction and the parse
+    /// options read fractional values. The integer offsets and advance are then rounded from them
+    pub fractional: Option<FractionalMetrics>,
 }
 
-impl CharacterInfo
-{
-    /// Creates a default character with no usable information
-    fn new() -> CharacterInfo
+impl CharacterInfo
+{
+    /// Creates a default character with no usable information
+    fn new() -> CharacterInfo
+    {
+        CharacterInfo
+        {
+            id: None,
+            x: None,
+            y: None,
+            width: None,
+            height: None,
+            x_offset: None,
+            y_offset: None,
+            x_advance: None,
+            page: None,
+            chnl: None,
+            rot: None,
+            texture_coordinates: [Uv(0.0, 0.0); 4],
+            fractional: None,
+        }
+    }
+
+    /// Determines if the texture coordinates of this character and the other character are equal,
+    /// allowing for the given amount of floating point error
+    ///
+    /// `other` - the character whose texture coordinates are compared against
+    /// `ulps` - the maximum number of units in the last place the coordinates are allowed to differ by
+    pub fn texture_coords_approx_eq(&self, other: &CharacterInfo, ulps: i32) -> bool
+    {
+        self.texture_coordinates.iter().zip(other.texture_coordinates.iter()).all(|(this_coord, other_coord)|
+            {
+                approx_eq!(f32, this_coord.0, other_coord.0, ulps = ulps) && approx_eq!(f32, this_coord.1, other_coord.1, ulps = ulps)
+            })
+    }
+
+    /// Finds the texture coordinates of the character moved inwards on all sides by the given
+    /// number of texels. Sampling slightly inside of the character prevents neighbouring characters
+    /// of the texture atlas from bleeding into it; an inset of half a texel is typical
+    ///
+    /// `atlas_dimensions` - the size of the texture atlas the texture coordinates were found with
+    /// `texels` - how far to move each side inwards, in texels of the atlas
+    pub fn texture_coords_inset(&self, atlas_dimensions: AtlasDimensions, texels: f32) -> [Uv; 4]
+    {
+        let mut texture_coordinates = self.texture_coordinates;
+
+        if atlas_dimensions.width <= 0 || atlas_dimensions.height <= 0
+        {
+            return texture_coordinates;
+        }
+
+        let inset_x = texels / atlas_dimensions.width as f32;
+        let inset_y = texels / atlas_dimensions.height as f32;
+        // The corners are moved based on where they are in the atlas, which is not where they are
+        // on the character if it was rotated
+        let [top_left, top_right, bottom_left, bottom_right] = self.atlas_corners();
+
+        // The top of the character has the larger y texture coordinate, so it moves down
+        texture_coordinates[top_left].0 += inset_x;
+        texture_coordinates[top_left].1 -= inset_y;
+        texture_coordinates[top_right].0 -= inset_x;
+        texture_coordinates[top_right].1 -= inset_y;
+        texture_coordinates[bottom_left].0 += inset_x;
+        texture_coordinates[bottom_left].1 += inset_y;
+        texture_coordinates[bottom_right].0 -= inset_x;
+        texture_coordinates[bottom_right].1 += inset_y;
+
+        texture_coordinates
+    }
+
+    /// Finds the texture coordinates of the character with the given number of pixels trimmed from
+    /// every side of its area of the atlas, such as the padding a distance field exporter adds
+    /// around each character. Unlike texture_coords_inset, the area is trimmed before the
+    /// coordinates are found, so they are at exact pixel edges. The area is treated as half-open,
+    /// the same as the default parse options. Characters without an area of the atlas, negative
+    /// padding and padding that would leave nothing of the area do not have coordinates
+    ///
+    /// `atlas_dimensions` - the size of the texture atlas to find the texture coordinates with
+    /// `pad` - how many pixels to trim from each side of the area
+    pub fn texture_coords_trimmed(&self, atlas_dimensions: AtlasDimensions, pad: i32) -> Option<[Uv; 4]>
+    {
+        let (x, y, width, height) = self.require_rect().ok()?;
+        let trimmed = pad.checked_mul(2)?;
+
+        if pad < 0 || width <= trimmed || height <= trimmed || atlas_dimensions.width <= 0 || atlas_dimensions.height <= 0
+        {
+            return None;
+        }
+
+        // Trimming the same amount from every side leaves a rotated area rotated the same way
+        let mut trimmed_char = self.clone();
+        // Areas at the far edge of the i32 range cannot be moved inwards
+        trimmed_char.x = Some(x.checked_add(pad)?);
+        trimmed_char.y = Some(y.checked_add(pad)?);
+        trimmed_char.width = Some(width - trimmed);
+        trimmed_char.height = Some(height - trimmed);
+        calculate_char_texture_coords(&mut trimmed_char, atlas_dimensions, RectEdges::HalfOpen);
+
+        Some(trimmed_char.texture_coordinates)
+    }
+
+    /// Finds the area of the atlas the character is in as texture coordinates, as the smallest u,
+    /// smallest v, largest u and largest v, which is the form used by most sprite drawing code. As
+    /// v increases upward, the smallest v is at the bottom of the character. The area of a rotated
+    /// character is also rotated. Characters without an area of the atlas do not have one
+    pub fn uv_rect(&self) -> Option<(f32, f32, f32, f32)>
+    {
+        if !self.is_renderable()
+        {
+            return None;
+        }
+
+        let (u, v) = (self.texture_coordinates.map(|x| x.0), self.texture_coordinates.map(|x| x.1));
+        let min = |values: [f32; 4]| values.into_iter().fold(f32::INFINITY, f32::min);
+        let max = |values: [f32; 4]| values.into_iter().fold(f32::NEG_INFINITY, f32::max);
+
+        Some((min(u), min(v), max(u), max(v)))
+    }
+
+    /// Determines if the packer of the atlas rotated the character. Characters without a rot
+    /// parameter are upright
+    pub fn is_rotated(&self) -> bool
+    {
+        self.rot.unwrap_or(0) != 0
+    }
+
+    /// Finds which of the texture coordinates are at the top left, top right, bottom left and
+    /// bottom right of the area of the character in the atlas, in that order
+    fn atlas_corners(&self) -> [usize; 4]
+    {
+        if self.is_rotated()
+        {
+            [BOTTOM_LEFT_INDEX, TOP_LEFT_INDEX, BOTTOM_RIGHT_INDEX, TOP_RIGHT_INDEX]
+        }
+        else
+        {
+            [TOP_LEFT_INDEX, TOP_RIGHT_INDEX, BOTTOM_LEFT_INDEX, BOTTOM_RIGHT_INDEX]
+        }
+    }
+
+    /// Finds the position of the top left corner of the character in the texture atlas, as the x
+    /// and y in pixels, if both are present
+    pub fn position(&self) -> Option<PixelPos>
+    {
+        Some(PixelPos(self.x?, self.y?))
+    }
+
+    /// Finds the size of the character in pixels, as the width and height, if both are present
+    pub fn size(&self) -> Option<(i32, i32)>
+    {
+        Some((self.width?, self.height?))
+    }
+
+    /// Finds how far the character is drawn from the position of the pen, as the x and y offsets in
+    /// pixels, if both are present
+    pub fn offset(&self) -> Option<(i32, i32)>
+    {
+        Some((self.x_offset?, self.y_offset?))
+    }
+
+    /// Finds the area of the texture atlas the character is in, as the x, y, width and height in
+    /// pixels. The error says which of these values is missing
+    pub fn require_rect(&self) -> Result<(i32, i32, i32, i32), MissingField>
+    {
+        let require = |value: Option<i32>, field| value.ok_or(MissingField { id: self.id, field });
+
+        Ok((require(self.x, "x")?, require(self.y, "y")?, require(self.width, "width")?, require(self.height, "height")?))
+    }
+
+    /// Determines if the character has all of the values needed to draw it from the texture atlas
+    pub fn is_renderable(&self) -> bool
     {
-        CharacterInfo
+        self.require_rect().is_ok()
+    }
+
+    /// Determines if the character is stored in only one colour channel of the texture atlas, which
+    /// is the case for fonts that pack characters into each channel. Other characters use all of
+    /// the channels
+    pub fn is_packed_single_channel(&self) -> bool
+    {
+        self.channel_index().is_some()
+    }
+
+    /// Finds the colour channel of the texture atlas the character is stored in, as an index into
+    /// the colour in RGBA order. Characters that use all channels, or whose channel is unknown,
+    /// do not have an index
+    pub fn channel_index(&self) -> Option<usize>
+    {
+        // The chnl parameter is a bit field in the order of blue, green, red and alpha
+        match self.chnl?
         {
-            id: None,
-            x: None,
-            y: None,
-            width: None,
-            height: None,
-            x_offset: None,
-            y_offset: None,
-            x_advance: None,
-            page: None,
-            chnl: None,
-            texture_coordinates: [(0.0, 0.0); 4],
+            1 => Some(2),
+            2 => Some(1),
+            4 => Some(0),
+            8 => Some(3),
+            _ => None
         }
     }
+
+    /// Converts the id of the character into the character it represents, treating the id as a
+    /// Unicode code point. This is only correct for unicode fonts; see Font::char_of for a
+    /// conversion that takes the font into account
+    pub fn as_char(&self) -> Option<char>
+    {
+        char::from_u32(u32::try_from(self.id?).ok()?)
+    }
 }
 
 #[cfg(test)]
 mod tests
 {
     use std::env;
+    use std::fs;
     use std::path::PathBuf;
-    use crate::{AtlasDimensions, CharacterInfo, extract_characters, TOP_LEFT_INDEX, TOP_RIGHT_INDEX, BOTTOM_LEFT_INDEX, BOTTOM_RIGHT_INDEX};
+    use proptest::prelude::*;
+    use crate::{AtlasDimensions, CharConflict, CharacterInfo, DuplicateChars, extract_characters, find_character, find_character_with_options, Font, extract_numeric_value, parse_into, MissingField, parse_str, ParseStats, RectEdges, parse_bytes, parse_file, parse_file_with_diagnostics, parse_file_with_options, parse_reader_with_diagnostics, parse_reader_with_options, ParseError, ParseOptions, PixelPos, Uv, TOP_LEFT_INDEX, TOP_RIGHT_INDEX, BOTTOM_LEFT_INDEX, BOTTOM_RIGHT_INDEX};
     use float_cmp::approx_eq;
 
     const ATLAS_WIDTH: i32 = 512;
@@ -274,7 +1355,7 @@ mod tests
         validate_first_char_tex_coords(&characters[0]);
         validate_third_char_tex_coords(&characters[2]);
 
-        assert_eq!([(0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0)], second_char.texture_coordinates);
+        assert_eq!([Uv(0.0, 0.0); 4], second_char.texture_coordinates);
     }
 
     #[test]
@@ -302,7 +1383,7 @@ mod tests
         validate_first_char_tex_coords(&characters[0]);
         validate_third_char_tex_coords(&characters[2]);
 
-        assert_eq!([(0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0)], second_char.texture_coordinates);
+        assert_eq!([Uv(0.0, 0.0); 4], second_char.texture_coordinates);
     }
 
     #[test]
@@ -348,6 +1429,765 @@ mod tests
         validate_third_char_tex_coords(&characters[2]);
     }
 
+    #[test]
+    fn check_texture_coords_approx_eq()
+    {
+        let test_file = get_test_folder().join("validFormat.fnt");
+        let characters = extract_characters(&test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        let characters_copy = extract_characters(&test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+
+        assert!(characters[0].texture_coords_approx_eq(&characters_copy[0], 2));
+        assert!(characters[2].texture_coords_approx_eq(&characters_copy[2], 2));
+        assert!(!characters[0].texture_coords_approx_eq(&characters[2], 2));
+    }
+
+    #[test]
+    fn check_require_rect()
+    {
+        let test_file = get_test_folder().join("missing_parameters.fnt");
+        let characters = extract_characters(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+
+        assert_eq!(Ok((0, 0, 22, 72)), characters[0].require_rect());
+        assert!(characters[0].is_renderable());
+
+        let missing = characters[1].require_rect().unwrap_err();
+        assert_eq!(MissingField{ id: Some(32), field: "x" }, missing);
+        assert_eq!("glyph 32 missing x", missing.to_string());
+        assert!(!characters[1].is_renderable());
+    }
+
+    #[test]
+    fn check_zero_sized_atlas()
+    {
+        let test_file = get_test_folder().join("validFormat.fnt");
+
+        for (width, height) in [(0, 0), (512, 0), (0, 512), (-512, 512)]
+        {
+            let result = extract_characters(&test_file, AtlasDimensions{ width, height });
+            assert!(matches!(result, Err(ParseError::InvalidAtlas { width: w, height: h }) if (w, h) == (width, height)));
+        }
+
+        let mut binary = Vec::new();
+        parse_file(&test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap().write_binary(&mut binary).unwrap();
+        assert!(matches!(parse_bytes(&binary, AtlasDimensions{ width: 0, height: 0 }), Err(ParseError::InvalidAtlas { .. })));
+    }
+
+    proptest!
+    {
+        #[test]
+        fn check_arbitrary_bytes(data in proptest::collection::vec(any::<u8>(), 0..512), width in -2..1024, height in -2..1024)
+        {
+            if let Ok(font) = parse_bytes(&data, AtlasDimensions{ width, height })
+            {
+                prop_assert!(font.characters.iter().all(|x| x.texture_coordinates.iter().all(|y| y.0.is_finite() && y.1.is_finite())));
+            }
+        }
+
+        #[test]
+        fn check_arbitrary_binary_blocks(blocks in proptest::collection::vec(any::<u8>(), 0..512))
+        {
+            // Starting with a valid header gets past the identifier checks and into the blocks
+            let mut data = b"BMF\x03".to_vec();
+            data.extend(blocks);
+            let _ = parse_bytes(&data, AtlasDimensions{ width: 512, height: 512 });
+        }
+
+        #[test]
+        fn check_arbitrary_lines(lines in proptest::collection::vec("(info|common|page|char id|kerning|chars)( [a-zA-Z]{0,8}(=\"?[-0-9,]{0,12}\"?)?){0,12}", 0..16))
+        {
+            let _ = parse_bytes(lines.join("\n").as_bytes(), AtlasDimensions{ width: 512, height: 512 });
+        }
+    }
+
+    #[test]
+    fn check_empty_values()
+    {
+        assert_eq!(None, extract_numeric_value("xadvance="));
+        assert_eq!(None, extract_numeric_value("xadvance==32"));
+        assert_eq!(Some(("xadvance".to_string(), 32)), extract_numeric_value("xadvance=32"));
+
+        let contents = "info face=\"a=b\" size=\nchar id=65 x=0 y=0 width=10 height=10 xadvance=\n";
+        let (font, errors) = parse_reader_with_diagnostics(contents.as_bytes(), AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }, &ParseOptions::default()).unwrap();
+
+        // Quoted values may contain '=' as they are only split on the first one
+        assert_eq!(Some("a=b".to_string()), font.info.face);
+        assert_eq!(None, font.info.size);
+        assert_eq!(None, font.characters[0].x_advance);
+        assert_eq!(Some(10), font.characters[0].width);
+
+        let parameters = errors.iter().map(|x| match x
+            {
+                ParseError::InvalidParameter { line_number, parameter } => (*line_number, parameter.as_str()),
+                _ => panic!("Unexpected error {}", x)
+            }).collect::<Vec<(usize, &str)>>();
+        assert_eq!(vec![(1, "size="), (2, "xadvance=")], parameters);
+    }
+
+    #[test]
+    fn check_comments_and_blank_lines()
+    {
+        let contents = "# Exported by hand\n\
+                        info face=\"Arial\" size=57\n\
+                        \n\
+                        common lineHeight=82 base=53\n\
+                        #char id=65 x=0 y=0 width=10 height=10\n\
+                        \t\n\
+                        char id=124 x=0 y=0 width=22 height=72\n\
+                        \x20  # char id=66 x=0 y=0 width=10 height=10\n";
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+        let (font, errors) = parse_reader_with_diagnostics(contents.as_bytes(), AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }, &strict).unwrap();
+
+        assert!(errors.is_empty());
+        assert_eq!(Some(57), font.info.size);
+        assert_eq!(Some(82), font.common.line_height);
+        assert_eq!(vec![Some(124)], font.characters.iter().map(|x| x.id).collect::<Vec<Option<i32>>>());
+
+        // A file of only comments has no font lines
+        let result = parse_reader_with_diagnostics("# char id=65\n\n".as_bytes(), AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }, &strict);
+        assert!(matches!(result, Err(ParseError::NotABmFont)));
+    }
+
+    #[test]
+    fn check_parse_stats()
+    {
+        let test_file = get_test_folder().join("unrecognized_line.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(ParseStats{ chars: 2, kernings: 0, skipped: 1, errored: 0, info_found: true, common_found: true }, font.stats);
+
+        let test_file = get_test_folder().join("incorrect_format.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(3, font.stats.chars);
+        assert_eq!(1, font.stats.errored);
+
+        let contents = "kerning first=32 second=65 amount=-1\nkerning first=32 amount=-1\n";
+        let font = parse_str(contents, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(ParseStats{ kernings: 1, ..ParseStats::default() }, font.stats);
+    }
+
+    #[test]
+    fn check_texture_coords_inset()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let test_file = get_test_folder().join("validFormat.fnt");
+        let characters = extract_characters(test_file, atlas_dimensions).unwrap();
+
+        let inset = characters[0].texture_coords_inset(atlas_dimensions, 0.5);
+        let half_texel_x = 0.5 / ATLAS_WIDTH as f32;
+        let half_texel_y = 0.5 / ATLAS_HEIGHT as f32;
+
+        assert!(approx_eq!(f32, inset[TOP_LEFT_INDEX].0, half_texel_x, ulps = 2));
+        assert!(approx_eq!(f32, inset[TOP_LEFT_INDEX].1, 1.0 - half_texel_y, ulps = 2));
+        assert!(approx_eq!(f32, inset[BOTTOM_RIGHT_INDEX].0, (22_f32 / ATLAS_WIDTH as f32) - half_texel_x, ulps = 2));
+        assert!(approx_eq!(f32, inset[BOTTOM_RIGHT_INDEX].1, 1.0 - (72_f32 / ATLAS_HEIGHT as f32) + half_texel_y, ulps = 2));
+
+        assert_eq!(characters[0].texture_coordinates, characters[0].texture_coords_inset(atlas_dimensions, 0.0));
+    }
+
+    #[test]
+    fn check_texture_coords_trimmed()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let test_file = get_test_folder().join("validFormat.fnt");
+        let characters = extract_characters(test_file, atlas_dimensions).unwrap();
+
+        // The first character is at 0, 0 and is 22 by 72 pixels
+        let trimmed = characters[0].texture_coords_trimmed(atlas_dimensions, 8).unwrap();
+        assert!(approx_eq!(f32, trimmed[TOP_LEFT_INDEX].0, 8_f32 / ATLAS_WIDTH as f32, ulps = 2));
+        assert!(approx_eq!(f32, trimmed[TOP_LEFT_INDEX].1, 1.0 - (8_f32 / ATLAS_HEIGHT as f32), ulps = 2));
+        assert!(approx_eq!(f32, trimmed[BOTTOM_RIGHT_INDEX].0, 14_f32 / ATLAS_WIDTH as f32, ulps = 2));
+        assert!(approx_eq!(f32, trimmed[BOTTOM_RIGHT_INDEX].1, 1.0 - (64_f32 / ATLAS_HEIGHT as f32), ulps = 2));
+
+        assert_eq!(Some(characters[0].texture_coordinates), characters[0].texture_coords_trimmed(atlas_dimensions, 0));
+        assert_eq!(None, characters[0].texture_coords_trimmed(atlas_dimensions, 11));
+        assert_eq!(None, characters[0].texture_coords_trimmed(atlas_dimensions, -1));
+        assert_eq!(None, CharacterInfo::new().texture_coords_trimmed(atlas_dimensions, 0));
+
+        let mut far_character = characters[0].clone();
+        far_character.x = Some(i32::MAX);
+        assert_eq!(None, far_character.texture_coords_trimmed(atlas_dimensions, 1));
+        far_character.x = Some(0);
+        far_character.y = Some(i32::MAX);
+        assert_eq!(None, far_character.texture_coords_trimmed(atlas_dimensions, 1));
+        assert!(far_character.texture_coords_trimmed(atlas_dimensions, 0).is_some());
+    }
+
+    #[test]
+    fn check_channels()
+    {
+        let mut character = CharacterInfo::new();
+
+        for (chnl, channel_index) in [(1, Some(2)), (2, Some(1)), (4, Some(0)), (8, Some(3)), (15, None), (0, None)]
+        {
+            character.chnl = Some(chnl);
+            assert_eq!(channel_index, character.channel_index());
+            assert_eq!(channel_index.is_some(), character.is_packed_single_channel());
+        }
+
+        character.chnl = None;
+        assert!(!character.is_packed_single_channel());
+    }
+
+    #[test]
+    fn check_lenient_numbers()
+    {
+        let contents = "info face=\"Arial 12px\" size=57px padding=8,8,8,8px\n\
+                        common lineHeight=82px base=53\n\
+                        char id=65 x=0 y=0 width=22px height=72 xoffset=-3px yoffset=3 xadvance=32px page=0\n";
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+
+        let (font, errors) = parse_reader_with_diagnostics(contents.as_bytes(), atlas_dimensions, &ParseOptions::default()).unwrap();
+        assert_eq!(None, font.characters[0].x_advance);
+        assert_eq!(6, errors.len());
+
+        let lenient = ParseOptions{ lenient_numbers: true, ..ParseOptions::default() };
+        let (font, errors) = parse_reader_with_diagnostics(contents.as_bytes(), atlas_dimensions, &lenient).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(Some(32), font.characters[0].x_advance);
+        assert_eq!(Some(22), font.characters[0].width);
+        assert_eq!(Some(-3), font.characters[0].x_offset);
+        assert_eq!(Some(82), font.common.line_height);
+        assert_eq!(Some(57), font.info.size);
+        assert_eq!(Some([8, 8, 8, 8]), font.info.padding);
+
+        // Quoted values are not numbers, and so are kept as they are
+        assert_eq!(Some("Arial 12px".to_string()), font.info.face);
+    }
+
+    #[test]
+    fn check_file_order()
+    {
+        let contents = "char id=66 x=0 y=0 width=10 height=10\n\
+                        char id=65 x=10 y=0 width=10 height=10\n\
+                        char id=67 x=20 y=0 width=10 height=10\n\
+                        char id=65 x=30 y=0 width=10 height=10\n";
+        let font = parse_str(contents, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+
+        // Characters are neither sorted nor deduplicated
+        let characters = font.characters.iter().map(|x| (x.id.unwrap(), x.x.unwrap())).collect::<Vec<(i32, i32)>>();
+        assert_eq!(vec![(66, 0), (65, 10), (67, 20), (65, 30)], characters);
+
+        // The binary format keeps the same order
+        let mut written = Vec::new();
+        font.write_binary(&mut written).unwrap();
+        let read_back = parse_bytes(&written, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(characters, read_back.characters.iter().map(|x| (x.id.unwrap(), x.x.unwrap())).collect::<Vec<(i32, i32)>>());
+    }
+
+    #[test]
+    fn check_missing_size_as_zero()
+    {
+        let contents = "char id=32 x=0 y=0 xadvance=32\nchar id=65 x=0 y=0 width=abc\n";
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+
+        let font = parse_str(contents, atlas_dimensions).unwrap();
+        assert_eq!(None, font.characters[0].width);
+        assert!(!font.characters[0].is_renderable());
+
+        let options = ParseOptions{ missing_size_as_zero: true, ..ParseOptions::default() };
+        let (font, errors) = parse_reader_with_diagnostics(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(Some(0), font.characters[0].width);
+        assert_eq!(Some(0), font.characters[0].height);
+        assert_eq!(Ok((0, 0, 0, 0)), font.characters[0].require_rect());
+
+        // The width of the second character is present but invalid, so it is not assumed to be zero
+        assert_eq!(None, font.characters[1].width);
+        assert_eq!(Some(0), font.characters[1].height);
+        assert_eq!(1, errors.len());
+    }
+
+    #[test]
+    fn check_unknown_lines()
+    {
+        let test_file = get_test_folder().join("unrecognized_line.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(1, font.unknown_lines.len());
+        assert!(font.unknown_lines[0].starts_with("char isdfd=32"));
+
+        let contents = "info face=\"Arial\"\n# comment\n\nlayer id=0 name=\"outline\"\nchar id=65\n";
+        let font = parse_str(contents, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(vec!["layer id=0 name=\"outline\"".to_string()], font.unknown_lines);
+    }
+
+    #[test]
+    fn check_inclusive_rect_edges()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let contents = "char id=65 x=0 y=0 width=33 height=65\n";
+
+        let half_open = parse_str(contents, atlas_dimensions).unwrap();
+        assert_eq!(Uv(33.0 / ATLAS_WIDTH as f32, 1.0 - 65.0 / ATLAS_HEIGHT as f32), half_open.characters[0].texture_coordinates[BOTTOM_RIGHT_INDEX]);
+
+        let options = ParseOptions{ rect_edges: RectEdges::Inclusive, ..ParseOptions::default() };
+        let (inclusive, _) = parse_reader_with_diagnostics(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(Uv(32.0 / ATLAS_WIDTH as f32, 1.0 - 64.0 / ATLAS_HEIGHT as f32), inclusive.characters[0].texture_coordinates[BOTTOM_RIGHT_INDEX]);
+        assert_eq!(Uv(0.0, 1.0), inclusive.characters[0].texture_coordinates[TOP_LEFT_INDEX]);
+    }
+
+    #[test]
+    fn check_grouped_accessors()
+    {
+        let test_file = get_test_folder().join("missing_parameters.fnt");
+        let characters = extract_characters(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+
+        assert_eq!(Some(PixelPos(0, 0)), characters[0].position());
+        assert_eq!(Some((22, 72)), characters[0].size());
+        assert_eq!(Some((-3, 3)), characters[0].offset());
+
+        // The second character is missing its x and y
+        assert_eq!(None, characters[1].position());
+        assert_eq!(Some((0, 0)), characters[1].size());
+        assert_eq!(Some((0, 53)), characters[1].offset());
+    }
+
+    #[test]
+    fn check_rotated_character()
+    {
+        let contents = "char id=65 x=0 y=0 width=32 height=64 rot=1\n\
+                        char id=66 x=0 y=0 width=32 height=64 rot=0\n\
+                        char id=67 x=40 y=0 width=10 height=10\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 128, height: 128 }).unwrap();
+
+        // The rotated character takes up an area 64 pixels wide and 32 pixels tall, with the top of
+        // the character on the right
+        let rotated = &font.characters[0];
+        assert!(rotated.is_rotated());
+        assert_eq!(Uv(0.5, 1.0), rotated.texture_coordinates[TOP_LEFT_INDEX]);
+        assert_eq!(Uv(0.5, 0.75), rotated.texture_coordinates[TOP_RIGHT_INDEX]);
+        assert_eq!(Uv(0.0, 1.0), rotated.texture_coordinates[BOTTOM_LEFT_INDEX]);
+        assert_eq!(Uv(0.0, 0.75), rotated.texture_coordinates[BOTTOM_RIGHT_INDEX]);
+
+        let inset = rotated.texture_coords_inset(AtlasDimensions{ width: 128, height: 128 }, 1.0);
+        assert_eq!(Uv(0.5 - 1.0 / 128.0, 1.0 - 1.0 / 128.0), inset[TOP_LEFT_INDEX]);
+        assert_eq!(Uv(1.0 / 128.0, 0.75 + 1.0 / 128.0), inset[BOTTOM_RIGHT_INDEX]);
+
+        // Without a rotation, or with a rotation of zero, the character is upright
+        let upright = &font.characters[1];
+        assert!(!upright.is_rotated());
+        assert_eq!(Uv(0.0, 1.0), upright.texture_coordinates[TOP_LEFT_INDEX]);
+        assert_eq!(Uv(0.25, 0.5), upright.texture_coordinates[BOTTOM_RIGHT_INDEX]);
+
+        // Only the wider area of the rotated character reaches the third character
+        let overlaps = font.find_overlaps().iter().map(|x| (x.first, x.second)).collect::<Vec<(usize, usize)>>();
+        assert_eq!(vec![(0, 1), (0, 2)], overlaps);
+    }
+
+    #[test]
+    fn check_field_aliases()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let contents = "char id=65 x=0 y=0 w=10 height=12 advance=11\n";
+
+        // Without the aliases, the renamed parameters are ignored the same as misspelled ones
+        let font = parse_str(contents, atlas_dimensions).unwrap();
+        assert_eq!(None, font.characters[0].x_advance);
+
+        let mut options = ParseOptions{ missing_size_as_zero: true, ..ParseOptions::default() };
+        options.field_aliases.insert("advance".to_string(), "xadvance".to_string());
+        options.field_aliases.insert("w".to_string(), "width".to_string());
+
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(Some(11), font.characters[0].x_advance);
+        // The width is not missing, as it was written with its alias
+        assert_eq!(Some(10), font.characters[0].width);
+        assert_eq!(Some(12), font.characters[0].height);
+    }
+
+    #[test]
+    fn check_case_insensitive_names()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let contents = "char id=65 X=1 y=2 Width=10 HEIGHT=12 XAdvance=11 Adv=3\n";
+
+        // By default the names must match exactly
+        let font = parse_str(contents, atlas_dimensions).unwrap();
+        assert_eq!((None, Some(2), None), (font.characters[0].x, font.characters[0].y, font.characters[0].x_advance));
+
+        let mut options = ParseOptions{ case_insensitive_names: true, ..ParseOptions::default() };
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(Some(PixelPos(1, 2)), font.characters[0].position());
+        assert_eq!(Some((10, 12)), font.characters[0].size());
+        assert_eq!(Some(11), font.characters[0].x_advance);
+
+        // Aliases are also matched regardless of case
+        options.field_aliases.insert("adv".to_string(), "xoffset".to_string());
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(Some(3), font.characters[0].x_offset);
+    }
+
+    #[test]
+    fn check_merge_duplicate_chars()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 128, height: 128 };
+        let contents = "char id=65 x=0 y=0 xadvance=11\n\
+                        char id=66 x=10 y=0 width=10 height=10\n\
+                        char id=65 x=0 y=0 width=32 height=64 xadvance=12\n";
+
+        // By default, both listings of the character are kept
+        let font = parse_str(contents, atlas_dimensions).unwrap();
+        assert_eq!(3, font.characters.len());
+
+        let merge = ParseOptions{ duplicate_chars: DuplicateChars::Merge, ..ParseOptions::default() };
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &merge).unwrap();
+        assert_eq!(2, font.characters.len());
+        assert_eq!(3, font.stats.chars);
+
+        // The size comes from the later listing, the conflicting advance from the first
+        let merged = font.glyph(65).unwrap();
+        assert_eq!(Some((32, 64)), merged.size());
+        assert_eq!(Some(11), merged.x_advance);
+        assert_eq!(Uv(0.25, 0.5), merged.texture_coordinates[BOTTOM_RIGHT_INDEX]);
+        assert_eq!(vec![CharConflict{ id: 65, field: "xadvance", kept: 11, discarded: 12 }], font.char_conflicts);
+    }
+
+    #[test]
+    fn check_infer_atlas_dimensions()
+    {
+        let characters = extract_characters(get_test_folder().join("validFormat.fnt"), AtlasDimensions{ width: 1, height: 1 }).unwrap();
+
+        // The third character reaches 278 pixels right and 328 pixels down
+        assert_eq!(AtlasDimensions{ width: 512, height: 512 }, AtlasDimensions::infer(&characters));
+
+        let font = parse_str("char id=65 x=0 y=0 width=64 height=32 rot=1\nchar id=66 x=0 y=0", AtlasDimensions{ width: 1, height: 1 }).unwrap();
+        assert_eq!(AtlasDimensions{ width: 32, height: 64 }, AtlasDimensions::infer(&font.characters));
+        assert_eq!(AtlasDimensions{ width: 1, height: 1 }, AtlasDimensions::infer(&[]));
+    }
+
+    #[test]
+    fn check_assume_all_lines_are_chars()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let contents = "id=65 x=0 y=0 width=10 height=10\n\
+                        \n\
+                        glyph id=66 x=10 y=0 width=10 height=10\n";
+
+        // Without the option, none of the lines are recognized
+        assert!(matches!(parse_str(contents, atlas_dimensions), Err(ParseError::NotABmFont)));
+
+        let options = ParseOptions{ assume_all_lines_are_chars: true, ..ParseOptions::default() };
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(vec![(Some(65), Some(0)), (Some(66), Some(10))], font.characters.iter().map(|x| (x.id, x.x)).collect::<Vec<(Option<i32>, Option<i32>)>>());
+        assert_eq!(2, font.stats.chars);
+
+        // The other lines of a font are still read as what they are
+        let contents = "common lineHeight=32 base=26\n\
+                        id=65 x=0 y=0 width=10 height=10\n\
+                        id=66 x=10 y=0 width=10 height=10\n\
+                        kerning first=65 second=66 amount=-2\n";
+        let font = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &options).unwrap();
+        assert_eq!(Some(32), font.common.line_height);
+        assert_eq!(vec![Some(65), Some(66)], font.characters.iter().map(|x| x.id).collect::<Vec<Option<i32>>>());
+        assert_eq!(-2, font.kerning_amount(65, 66));
+        assert_eq!(2, font.stats.chars);
+    }
+
+    #[test]
+    fn check_uv_rect()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 256, height: 256 };
+        let font = parse_str("char id=65 x=64 y=0 width=64 height=128\nchar id=66 x=0 y=0 width=64 height=128 rot=1\nchar id=32 xadvance=10", atlas_dimensions).unwrap();
+
+        assert_eq!(Some((0.25, 0.5, 0.5, 1.0)), font.characters[0].uv_rect());
+
+        // The area of the rotated character is as wide as the character is tall
+        assert_eq!(Some((0.0, 0.75, 0.5, 1.0)), font.characters[1].uv_rect());
+        assert_eq!(None, font.characters[2].uv_rect());
+    }
+
+    #[test]
+    fn check_parse_into()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let contents = fs::read_to_string(get_test_folder().join("validFormat.fnt")).unwrap();
+
+        let mut characters = Vec::with_capacity(64);
+        characters.push(CharacterInfo::new());
+        let allocation = characters.as_ptr();
+
+        parse_into(contents.as_bytes(), atlas_dimensions, &mut characters).unwrap();
+        assert_eq!(extract_characters(get_test_folder().join("validFormat.fnt"), atlas_dimensions).unwrap(), characters);
+        assert_eq!(allocation, characters.as_ptr());
+
+        // Reading the font again replaces the characters rather than adding to them
+        parse_into(contents.as_bytes(), atlas_dimensions, &mut characters).unwrap();
+        assert_eq!(3, characters.len());
+        assert_eq!(allocation, characters.as_ptr());
+
+        // A font that cannot be read leaves the vector empty without freeing its allocation
+        assert!(matches!(parse_into("".as_bytes(), atlas_dimensions, &mut characters), Err(ParseError::NotABmFont)));
+        assert!(characters.is_empty());
+        assert!(characters.capacity() >= 64);
+        assert_eq!(allocation, characters.as_ptr());
+
+        assert!(matches!(parse_into(contents.as_bytes(), AtlasDimensions{ width: 0, height: 0 }, &mut characters), Err(ParseError::InvalidAtlas { .. })));
+        assert!(characters.capacity() >= 64);
+    }
+
+    #[test]
+    fn check_find_character()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let font = parse_file(get_test_folder().join("validFormat.fnt"), atlas_dimensions).unwrap();
+        let contents = std::fs::read_to_string(get_test_folder().join("validFormat.fnt")).unwrap();
+
+        // The first listing of the bar is found
+        assert_eq!(Some(font.characters[0].clone()), find_character(contents.as_bytes(), 124, atlas_dimensions).unwrap());
+        assert_eq!(Some(font.characters[1].clone()), find_character(contents.as_bytes(), 32, atlas_dimensions).unwrap());
+        assert_eq!(None, find_character(contents.as_bytes(), 65, atlas_dimensions).unwrap());
+
+        // Lines after the character are never read, so a line that cannot be read is not found
+        let contents = b"char id=65 x=0 y=0 width=10 height=10\n\xFF\xFE\n";
+        assert_eq!(Some(65), find_character(&contents[..], 65, atlas_dimensions).unwrap().unwrap().id);
+        assert!(matches!(find_character(&contents[..], 66, atlas_dimensions), Err(ParseError::LineRead { line_number: 2, .. })));
+
+        assert!(matches!(find_character("not a font".as_bytes(), 65, atlas_dimensions), Err(ParseError::NotABmFont)));
+
+        // The options are used to read the character that is found
+        let contents = "char id=66 X=5 advance=12\nchar id=65 X=abc advance=10px\n";
+        let mut options = ParseOptions{ case_insensitive_names: true, lenient_numbers: true, ..ParseOptions::default() };
+        options.field_aliases.insert("advance".to_string(), "xadvance".to_string());
+        let glyph = find_character_with_options(contents.as_bytes(), 65, atlas_dimensions, &options).unwrap().unwrap();
+        assert_eq!((Some(65), None, Some(10)), (glyph.id, glyph.x, glyph.x_advance));
+        assert_eq!(None, find_character(contents.as_bytes(), 65, atlas_dimensions).unwrap().unwrap().x_advance);
+
+        options.strict = true;
+        assert!(matches!(find_character_with_options(contents.as_bytes(), 65, atlas_dimensions, &options), Err(ParseError::InvalidParameter { line_number: 2, .. })));
+        assert_eq!(Some(5), find_character_with_options(contents.as_bytes(), 66, atlas_dimensions, &options).unwrap().unwrap().x);
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn check_send_sync()
+    {
+        // Fails to compile if any of the public types cannot be shared between threads, such as in
+        // an Arc<Font>
+        assert_send_sync::<Font>();
+        assert_send_sync::<CharacterInfo>();
+        assert_send_sync::<crate::FractionalMetrics>();
+        assert_send_sync::<crate::PackedCharacterInfo>();
+        assert_send_sync::<AtlasDimensions>();
+        assert_send_sync::<Uv>();
+        assert_send_sync::<PixelPos>();
+        assert_send_sync::<ParseError>();
+        assert_send_sync::<MissingField>();
+        assert_send_sync::<ParseOptions>();
+        assert_send_sync::<ParseStats>();
+        assert_send_sync::<crate::ParseOutcome>();
+        assert_send_sync::<crate::ParseWarning>();
+        assert_send_sync::<CharConflict>();
+        assert_send_sync::<crate::FontInfo>();
+        assert_send_sync::<crate::CommonInfo>();
+        assert_send_sync::<crate::PageInfo>();
+        assert_send_sync::<crate::KerningPair>();
+        assert_send_sync::<crate::FontDiff>();
+        assert_send_sync::<crate::GlyphOverlap>();
+        assert_send_sync::<crate::LayoutOptions>();
+        assert_send_sync::<crate::PositionedGlyph<'static>>();
+        assert_send_sync::<crate::ShapedGlyph<'static>>();
+        assert_send_sync::<crate::NdcQuad<'static>>();
+        assert_send_sync::<crate::MeasureCache<'static>>();
+    }
+
+    #[test]
+    fn check_empty_file()
+    {
+        let test_file = get_test_folder().join("empty.fnt");
+        let result = extract_characters(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT });
+        assert!(matches!(result, Err(ParseError::NotABmFont)));
+    }
+
+    #[test]
+    fn check_not_a_font_file()
+    {
+        let test_file = get_test_folder().join("not_a_font.fnt");
+        let result = extract_characters(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT });
+        assert!(matches!(result, Err(ParseError::NotABmFont)));
+    }
+
+    #[test]
+    fn check_no_characters()
+    {
+        let test_file = get_test_folder().join("no_characters.fnt");
+        let characters = extract_characters(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert!(characters.is_empty());
+    }
+
+    #[test]
+    fn check_missing_file()
+    {
+        let test_file = get_test_folder().join("does_not_exist.fnt");
+        let result = extract_characters(test_file, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT });
+        assert!(matches!(result, Err(ParseError::FileOpen { .. })));
+    }
+
+    #[test]
+    fn check_byte_encodings()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let test_file = get_test_folder().join("validFormat.fnt");
+        let contents = std::fs::read_to_string(&test_file).unwrap();
+        let expected = parse_file(&test_file, atlas_dimensions).unwrap();
+
+        let mut utf_8_bom = vec![0xEF, 0xBB, 0xBF];
+        utf_8_bom.extend(contents.as_bytes());
+        assert_eq!(expected, parse_bytes(&utf_8_bom, atlas_dimensions).unwrap());
+
+        let mut utf_16_le = vec![0xFF, 0xFE];
+        utf_16_le.extend(contents.encode_utf16().flat_map(|x| x.to_le_bytes()));
+        assert_eq!(expected, parse_bytes(&utf_16_le, atlas_dimensions).unwrap());
+
+        let mut utf_16_be = vec![0xFE, 0xFF];
+        utf_16_be.extend(contents.encode_utf16().flat_map(|x| x.to_be_bytes()));
+        assert_eq!(expected, parse_bytes(&utf_16_be, atlas_dimensions).unwrap());
+
+        assert!(matches!(parse_bytes(&utf_16_le[..utf_16_le.len() - 1], atlas_dimensions), Err(ParseError::InvalidEncoding(_))));
+    }
+
+    #[test]
+    fn check_ansi_face_name()
+    {
+        // A face name of "Café" stored using a single byte character set
+        let mut contents = b"info face=\"Caf".to_vec();
+        contents.push(0xE9);
+        contents.extend(b"\" size=32\nchar id=65 x=0 y=0 width=10 height=10");
+
+        let font = parse_bytes(&contents, AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT }).unwrap();
+        assert_eq!(Some("Café".to_string()), font.info.face);
+        assert_eq!(Some(65), font.characters[0].id);
+    }
+
+    #[test]
+    fn check_binary_detection()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let expected = parse_file(get_test_folder().join("kerning.fnt"), atlas_dimensions).unwrap();
+
+        let mut binary = Vec::new();
+        expected.write_binary(&mut binary).unwrap();
+        assert_eq!(expected.characters, parse_bytes(&binary, atlas_dimensions).unwrap().characters);
+    }
+
+    #[test]
+    fn check_non_square_atlas()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 1024, height: 512 };
+        let characters = extract_characters(get_test_folder().join("validFormat.fnt"), atlas_dimensions).unwrap();
+        let tex_coords = characters[2].texture_coordinates;
+
+        assert!(approx_eq!(f32, 2.0, atlas_dimensions.aspect_ratio(), ulps = 2));
+
+        assert!(approx_eq!(f32, 256.0 / 1024.0, tex_coords[TOP_LEFT_INDEX].0, ulps = 2));
+        assert!(approx_eq!(f32, 1.0 - 256.0 / 512.0, tex_coords[TOP_LEFT_INDEX].1, ulps = 2));
+        assert!(approx_eq!(f32, (256.0 + 22.0) / 1024.0, tex_coords[BOTTOM_RIGHT_INDEX].0, ulps = 2));
+        assert!(approx_eq!(f32, 1.0 - (256.0 + 72.0) / 512.0, tex_coords[BOTTOM_RIGHT_INDEX].1, ulps = 2));
+
+        // Correcting the texture coordinate lengths by the aspect ratio gives back the pixel proportions
+        let length_x = tex_coords[TOP_RIGHT_INDEX].0 - tex_coords[TOP_LEFT_INDEX].0;
+        let length_y = tex_coords[TOP_LEFT_INDEX].1 - tex_coords[BOTTOM_LEFT_INDEX].1;
+        assert!(approx_eq!(f32, 22.0 / 72.0, length_x * atlas_dimensions.aspect_ratio() / length_y, epsilon = 0.0001));
+    }
+
+    #[test]
+    fn check_strict_parsing()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+
+        let result = parse_file_with_options(get_test_folder().join("incorrect_format.fnt"), atlas_dimensions, &strict);
+        match result
+        {
+            Err(ParseError::InvalidParameter { line_number, parameter }) =>
+                {
+                    assert_eq!(6, line_number);
+                    assert_eq!("yoffset=53xadvance=32", parameter);
+                },
+            _ => panic!("Expected an invalid parameter error, got {:?}", result)
+        }
+
+        // Unknown and misspelled parameters are not invalid, they are ignored
+        assert!(parse_file_with_options(get_test_folder().join("added_parameter.fnt"), atlas_dimensions, &strict).is_ok());
+        assert!(parse_file_with_options(get_test_folder().join("mispelled_parameters.fnt"), atlas_dimensions, &strict).is_ok());
+    }
+
+    #[test]
+    fn check_negative_page_ids()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let contents = "page id=-1 file=\"arial_0.png\"\n\
+                        char id=65 x=0 y=0 width=10 height=10 page=-2\n";
+
+        // Without a valid id the page is left out, and the character does not have a page
+        let font = parse_str(contents, atlas_dimensions).unwrap();
+        assert!(font.pages.is_empty());
+        assert_eq!(None, font.characters[0].page);
+        assert_eq!(Some(10), font.characters[0].width);
+
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+        let result = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &strict);
+        assert!(matches!(result, Err(ParseError::InvalidParameter { line_number: 1, ref parameter }) if parameter == "id=-1"), "{:?}", result);
+
+        let result = parse_reader_with_options(&contents.as_bytes()[contents.find('\n').unwrap() + 1..], atlas_dimensions, &strict);
+        assert!(matches!(result, Err(ParseError::InvalidParameter { line_number: 1, ref parameter }) if parameter == "page=-2"), "{:?}", result);
+    }
+
+    #[test]
+    fn check_value_overflow()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+        let contents = "common lineHeight=82\n\
+                        char id=65 x=0 y=0 width=99999999999 height=10\n";
+
+        let result = parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &strict);
+        match result
+        {
+            Err(ParseError::ValueOverflow { field, line }) =>
+                {
+                    assert_eq!("width", field);
+                    assert_eq!(2, line);
+                },
+            _ => panic!("Expected a value overflow error, got {:?}", result)
+        }
+
+        // A value that is not a number at all is still only an invalid parameter
+        let contents = "char id=65 x=0 y=0 width=abc height=10\n";
+        assert!(matches!(parse_reader_with_options(contents.as_bytes(), atlas_dimensions, &strict), Err(ParseError::InvalidParameter { .. })));
+
+        // Outside of strict mode the glyph is kept without the overflowing value
+        let font = parse_str("char id=65 x=0 y=0 width=-99999999999 height=10", atlas_dimensions).unwrap();
+        assert_eq!(None, font.characters[0].width);
+    }
+
+    #[test]
+    fn check_diagnostics()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: ATLAS_WIDTH, height: ATLAS_HEIGHT };
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+
+        // The strict option does not stop the diagnostics from finding every problem
+        let (font, line_errors) = parse_file_with_diagnostics(get_test_folder().join("incorrect_format.fnt"), atlas_dimensions, &strict).unwrap();
+        assert_eq!(3, font.characters.len());
+        assert_eq!(1, line_errors.len());
+
+        let contents = "info face=\"Arial\" size=big\n\
+                        char id=65 x=0 y=0 width=abc height=10\n\
+                        char id=66 x=10 y=0 width=10 height=10\n\
+                        char id=67 x=20 y=0 width=10 height=10 xadvance=1=2 page\n\
+                        kerning first=65 second=66 amount=-1.5\n";
+        let (font, line_errors) = parse_reader_with_diagnostics(contents.as_bytes(), atlas_dimensions, &ParseOptions::default()).unwrap();
+
+        assert_eq!(3, font.characters.len());
+        assert_eq!(Some(10), font.characters[1].width);
+        assert!(font.kernings.is_empty());
+
+        let reported = line_errors.iter().map(|x| match x
+            {
+                ParseError::InvalidParameter { line_number, parameter } => (*line_number, parameter.as_str()),
+                _ => panic!("Unexpected error {:?}", x)
+            }).collect::<Vec<(usize, &str)>>();
+        assert_eq!(vec![(1, "size=big"), (2, "width=abc"), (4, "xadvance=1=2"), (4, "page"), (5, "amount=-1.5")], reported);
+    }
+
     // *** Helper Functions ***
 
     fn get_test_folder() -> PathBuf
@@ -390,8 +2230,8 @@ mod tests
     {
         let char_min_x = 0.0_f32;
         let char_min_y = 1.0_f32;
-        let char_max_x = char_min_x + (22 as f32 / ATLAS_WIDTH as f32);
-        let char_max_y = char_min_y - (72 as f32 / ATLAS_HEIGHT as f32);
+        let char_max_x = char_min_x + (22_f32 / ATLAS_WIDTH as f32);
+        let char_max_y = char_min_y - (72_f32 / ATLAS_HEIGHT as f32);
 
         assert!(approx_eq!(f32, char_info.texture_coordinates[TOP_LEFT_INDEX].0, char_min_x, ulps = 2));
         assert!(approx_eq!(f32, char_info.texture_coordinates[TOP_LEFT_INDEX].1, char_min_y, ulps = 2));
@@ -430,8 +2270,8 @@ mod tests
     {
         let char_min_x = 0.5_f32;
         let char_min_y = 0.5_f32;
-        let char_max_x = char_min_x + (22 as f32 / ATLAS_WIDTH as f32);
-        let char_max_y = char_min_y - (72 as f32 / ATLAS_HEIGHT as f32);
+        let char_max_x = char_min_x + (22_f32 / ATLAS_WIDTH as f32);
+        let char_max_y = char_min_y - (72_f32 / ATLAS_HEIGHT as f32);
 
         assert!(approx_eq!(f32, char_info.texture_coordinates[TOP_LEFT_INDEX].0, char_min_x, ulps = 2));
         assert!(approx_eq!(f32, char_info.texture_coordinates[TOP_LEFT_INDEX].1, char_min_y, ulps = 2));
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use crate::Font;
+
+/// Remembers the widths of text measured with a font, so that text which is measured every frame,
+/// such as the labels of a user interface, is only measured once. The cache borrows the font, so
+/// the font cannot be changed while the cache is in use and the remembered widths are always
+/// those of the font. Every different text is kept until the cache is cleared or dropped
+#[derive(Debug, Clone)]
+pub struct MeasureCache<'a>
+{
+    font: &'a Font,
+    widths: HashMap<String, i32>,
+}
+
+impl Font
+{
+    /// Creates an empty cache for measuring text with this font. See MeasureCache
+    pub fn measure_cache(&self) -> MeasureCache<'_>
+    {
+        MeasureCache { font: self, widths: HashMap::new() }
+    }
+}
+
+impl MeasureCache<'_>
+{
+    /// Finds the width of the text, the same as measure_width, measuring the text only if it was
+    /// not measured before
+    ///
+    /// `text` - the text to find the width of
+    pub fn measure_width_cached(&mut self, text: &str) -> i32
+    {
+        if let Some(width) = self.widths.get(text)
+        {
+            return *width;
+        }
+
+        let width = self.font.measure_width(text);
+        self.widths.insert(text.to_string(), width);
+        width
+    }
+
+    /// Finds how many different texts have been measured
+    pub fn len(&self) -> usize
+    {
+        self.widths.len()
+    }
+
+    /// Determines if no text has been measured
+    pub fn is_empty(&self) -> bool
+    {
+        self.widths.is_empty()
+    }
+
+    /// Forgets the widths of all of the measured text, to free their memory
+    pub fn clear(&mut self)
+    {
+        self.widths.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, parse_file};
+
+    #[test]
+    fn check_measure_cache()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        let mut cache = font.measure_cache();
+        assert!(cache.is_empty());
+
+        assert_eq!(font.measure_width("| |"), cache.measure_width_cached("| |"));
+        assert_eq!(font.measure_width("| |"), cache.measure_width_cached("| |"));
+        assert_eq!(font.measure_width("|\n| |"), cache.measure_width_cached("|\n| |"));
+        assert_eq!(2, cache.len());
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(0, cache.measure_width_cached(""));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{CharacterInfo, Font, Uv};
+
+/// The metrics of a character in the shape used by rasterizing text crates such as fontdue, so
+/// that characters of an angel font can be fed into their layout and rendering code. Unlike the
+/// character, vertical positions are relative to the baseline with y increasing upward
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphMetrics
+{
+    /// The distance in pixels from the pen position to the left side of the character
+    pub xmin: i32,
+    /// The distance in pixels from the baseline up to the bottom of the character. Characters that
+    /// go below the baseline have a negative value
+    pub ymin: i32,
+    /// The width of the character in pixels
+    pub width: usize,
+    /// The height of the character in pixels
+    pub height: usize,
+    /// How far the pen moves after the character, in pixels
+    pub advance_width: f32,
+    /// The position of the top left corner of the character in its page of the texture atlas, in
+    /// pixels with y increasing downward
+    pub atlas_x: i32,
+    pub atlas_y: i32,
+    /// The page of the texture atlas the character is in
+    pub page: i32,
+    /// The texture coordinates of the character, in the same order as those of the character
+    pub texture_coordinates: [Uv; 4],
+}
+
+impl Font
+{
+    /// Finds the metrics of the character with the given id, relative to the baseline of the font.
+    /// Characters that are missing any of their area, offsets or advance do not have metrics. A
+    /// character without a page is on the first page, and a font without a base has its baseline
+    /// at the top of the line
+    ///
+    /// `id` - the id of the character
+    pub fn glyph_metrics(&self, id: i32) -> Option<GlyphMetrics>
+    {
+        glyph_metrics(self.glyph(id)?, self.baseline_offset())
+    }
+}
+
+/// Finds the metrics of the character, given the distance from the top of a line to the baseline
+fn glyph_metrics(char_info: &CharacterInfo, baseline_offset: i32) -> Option<GlyphMetrics>
+{
+    let (atlas_x, atlas_y, width, height) = char_info.require_rect().ok()?;
+    let (x_offset, y_offset) = char_info.offset()?;
+
+    Some(GlyphMetrics
+    {
+        xmin: x_offset,
+        // The y offset is the distance from the top of the line down to the top of the character
+        ymin: baseline_offset - (y_offset + height),
+        width: usize::try_from(width).ok()?,
+        height: usize::try_from(height).ok()?,
+        // Fractional advances keep their fraction, as the advance of the metrics is a float
+        advance_width: char_info.metrics_f32().x_advance?,
+        atlas_x,
+        atlas_y,
+        page: char_info.page.unwrap_or(0),
+        texture_coordinates: char_info.texture_coordinates,
+    })
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, parse_file, parse_str};
+
+    #[test]
+    fn check_glyph_metrics()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // The bar is 72 pixels tall and starts 3 pixels below the top of the line, with the
+        // baseline 53 pixels below the top of the line
+        let metrics = font.glyph_metrics(124).unwrap();
+        assert_eq!((-3, -22), (metrics.xmin, metrics.ymin));
+        assert_eq!((22, 72), (metrics.width, metrics.height));
+        assert_eq!(30.0, metrics.advance_width);
+        assert_eq!((0, 0, 0), (metrics.atlas_x, metrics.atlas_y, metrics.page));
+        assert_eq!(font.characters[0].texture_coordinates, metrics.texture_coordinates);
+
+        assert_eq!(None, font.glyph_metrics(65));
+
+        // Characters without an advance cannot be laid out by other crates
+        let font = parse_str("char id=65 x=0 y=0 width=10 height=10 xoffset=0 yoffset=0", AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(None, font.glyph_metrics(65));
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+/// How the right and bottom sides of the area of a character in the texture atlas are found
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum RectEdges
+{
+    /// The area ends just before x + width and y + height, as written by the angel font
+    /// generator. A character at x=0 with a width of 10 covers pixels 0 to 9, and its right texture
+    /// coordinate is at 10 pixels. This is the default
+    #[default]
+    HalfOpen,
+    /// The area ends at x + width - 1 and y + height - 1, as written by exporters that store the
+    /// last pixel of the character rather than the pixel after it
+    Inclusive,
+}
+
+/// What is done with char lines that have the same id as an earlier char line, such as in font
+/// files that were joined together
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum DuplicateChars
+{
+    /// Every char line is a separate character, even if its id was already used. This is the default
+    #[default]
+    KeepAll,
+    /// The later char lines are merged into the first char line with the same id, filling in the
+    /// values the first char line does not have. Values that are different are added to the char
+    /// conflicts of the font
+    Merge,
+}
+
+/// Changes how parts of a font file that do not follow the expected format are handled
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParseOptions
+{
+    /// If true, parsing stops with an error at the first parameter that could not be read, and
+    /// fonts with characters on pages without a page line are an error. Otherwise such parameters
+    /// are treated as if they were absent, and the rest of the file is still read
+    pub strict: bool,
+    /// If true, numbers followed by other characters, such as the 32px of xadvance=32px, are read
+    /// as just the number. Otherwise such values cannot be read
+    pub lenient_numbers: bool,
+    /// If true, a char line without a width or height has a width or height of zero, as is used
+    /// for characters that are never drawn. Otherwise, the same as any other missing parameter,
+    /// the width or height is not set. A width or height with an invalid value is never zero
+    pub missing_size_as_zero: bool,
+    /// How the sides of the character areas are found when calculating the texture coordinates.
+    /// Defaults to half-open areas
+    pub rect_edges: RectEdges,
+    /// Other names of the parameters of char lines, used by programs that write their own version
+    /// of the format. Each name in the file is mapped to the standard name it is read as, such as
+    /// "advance" to "xadvance". The standard names are id, x, y, width, height, xoffset, yoffset,
+    /// xadvance, page, chnl and rot, which are always understood. Defaults to no other names
+    pub field_aliases: HashMap<String, String>,
+    /// If true, the names of the parameters of char lines are matched regardless of their case,
+    /// such as XAdvance for xadvance. This includes the names of the field aliases. Otherwise the
+    /// names must match exactly
+    pub case_insensitive_names: bool,
+    /// What is done with char lines that have the same id as an earlier char line. Defaults to
+    /// keeping all of them
+    pub duplicate_chars: DuplicateChars,
+    /// If true, every line that is not blank, a comment or another type of line, such as an info,
+    /// common or kerning line, is read as a char line, whether it starts with "char id", another
+    /// word or a parameter. This is for files from tools that write the characters without the
+    /// char line type. Otherwise only lines that start with "char id" are characters
+    pub assume_all_lines_are_chars: bool,
+    /// If true, the offsets and advance of char lines can have fractions, such as the
+    /// xadvance=12.5 written by some distance field exporters. The exact values are kept in the
+    /// fractional metrics of the character, and the integer values are rounded from them.
+    /// Otherwise such values cannot be read
+    pub fractional_values: bool,
+}
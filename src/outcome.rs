@@ -0,0 +1,153 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::io::BufRead;
+use std::path::Path;
+use crate::{parse_file_with_diagnostics, parse_reader_with_diagnostics, AtlasDimensions, CharConflict, Font, GlyphOverlap, KerningPair, ParseError, ParseOptions};
+
+/// A font that could be read, along with every problem that was found in it that did not stop it
+/// from being read
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOutcome
+{
+    pub font: Font,
+    /// The problems found in the font, starting with those of the lines in the order of the lines
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// A problem with a font that was read anyway. The parts of the font with the problem are left
+/// out or kept as they were read, as described by each warning
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseWarning
+{
+    /// A parameter of a line could not be read, and so was left out. Line numbers start from 1
+    InvalidParameter { line_number: usize, parameter: String },
+    /// The value of a parameter is too large or too small to be stored, and so was left out. Line
+    /// numbers start from 1
+    ValueOverflow { field: String, line: usize },
+    /// A character is on a page that does not have a page line
+    MissingPage { char_id: Option<i32>, page: i32 },
+    /// A value of a duplicated character was discarded, as parse options that merge duplicated
+    /// characters were used
+    CharConflict(CharConflict),
+    /// A kerning pair was listed more than once with different amounts. The amount that was
+    /// replaced is the one given
+    KerningConflict(KerningPair),
+    /// A kerning pair is for a character the font does not have
+    DanglingKerning(KerningPair),
+    /// The areas of the texture atlas of two characters overlap
+    Overlap(GlyphOverlap),
+}
+
+impl Display for ParseWarning
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ParseWarning::InvalidParameter { line_number, parameter } => write!(f, "Unable to read parameter {} on line number {}", parameter, line_number),
+            ParseWarning::ValueOverflow { field, line } => write!(f, "The value of {} on line number {} does not fit in a 32 bit integer", field, line),
+            ParseWarning::MissingPage { char_id: Some(char_id), page } => write!(f, "Character {} is on page {}, which does not exist", char_id, page),
+            ParseWarning::MissingPage { char_id: None, page } => write!(f, "A character without an id is on page {}, which does not exist", page),
+            ParseWarning::CharConflict(conflict) => write!(f, "The {} of {} of duplicated character {} was discarded for {}", conflict.field, conflict.discarded, conflict.id, conflict.kept),
+            ParseWarning::KerningConflict(pair) => write!(f, "The kerning of {} between {} and {} was replaced", pair.amount, pair.first, pair.second),
+            ParseWarning::DanglingKerning(pair) => write!(f, "The kerning between {} and {} is for a character the font does not have", pair.first, pair.second),
+            ParseWarning::Overlap(overlap) => write!(f, "The characters at indexes {} and {} overlap in the texture atlas", overlap.first, overlap.second),
+        }
+    }
+}
+
+/// Reads as much of the passed in font file as possible, returning the font along with every
+/// problem found in it, rather than either stopping at the first problem or ignoring them.
+/// Problems that prevent the file from being read as a font at all, such as the file not existing,
+/// not being a font or having an invalid atlas size, are errors. Everything else is a warning:
+/// parameters that could not be read, characters on pages without a page line, conflicting
+/// duplicates of characters and kerning pairs, kerning pairs for missing characters and
+/// characters that overlap in the atlas
+///
+/// `file_location` - the location of the file in the angel file format
+/// `options` - how to handle the parts of the file that do not follow the expected format. The
+///             strict option is ignored, as otherwise only the first problem could be reported
+pub fn parse_file_with_outcome<A: AsRef<Path> + Debug + Clone>(file_location: A, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<ParseOutcome, ParseError>
+{
+    let (font, line_errors) = parse_file_with_diagnostics(file_location, atlas_dimensions, options)?;
+    Ok(ParseOutcome::new(font, line_errors))
+}
+
+/// Reads as much of the given source of a font as possible, returning the font along with every
+/// problem found in it. See `parse_file_with_outcome`
+///
+/// `reader` - the source of the font lines, such as an opened file or an in-memory buffer
+/// `options` - how to handle the parts of the font that do not follow the expected format. The
+///             strict option is ignored
+pub fn parse_reader_with_outcome<R: BufRead>(reader: R, atlas_dimensions: AtlasDimensions, options: &ParseOptions) -> Result<ParseOutcome, ParseError>
+{
+    let (font, line_errors) = parse_reader_with_diagnostics(reader, atlas_dimensions, options)?;
+    Ok(ParseOutcome::new(font, line_errors))
+}
+
+impl ParseOutcome
+{
+    /// Finds the problems of the font, given the problems of its lines found while reading it
+    fn new(font: Font, line_errors: Vec<ParseError>) -> ParseOutcome
+    {
+        // Only problems with parameters are reported for the lines that were read
+        let mut warnings = line_errors.into_iter().filter_map(|error| match error
+            {
+                ParseError::InvalidParameter { line_number, parameter } => Some(ParseWarning::InvalidParameter { line_number, parameter }),
+                ParseError::ValueOverflow { field, line } => Some(ParseWarning::ValueOverflow { field, line }),
+                _ => None
+            }).collect::<Vec<ParseWarning>>();
+
+        warnings.extend(font.missing_pages().into_iter().map(|(char_id, page)| ParseWarning::MissingPage { char_id, page }));
+
+        warnings.extend(font.char_conflicts.iter().cloned().map(ParseWarning::CharConflict));
+        warnings.extend(font.kerning_conflicts.iter().copied().map(ParseWarning::KerningConflict));
+        warnings.extend(font.dangling_kernings().into_iter().map(ParseWarning::DanglingKerning));
+        warnings.extend(font.find_overlaps().into_iter().map(ParseWarning::Overlap));
+
+        ParseOutcome { font, warnings }
+    }
+
+    /// Determines if no problems were found in the font
+    pub fn is_clean(&self) -> bool
+    {
+        self.warnings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use crate::{AtlasDimensions, GlyphOverlap, KerningPair, parse_file_with_outcome, parse_reader_with_outcome, ParseError, ParseOptions, ParseWarning};
+
+    #[test]
+    fn check_parse_outcome()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        assert!(parse_file_with_outcome(test_file, atlas_dimensions, &ParseOptions::default()).unwrap().is_clean());
+
+        let contents = "page id=0 file=\"first.png\"\n\
+                        char id=65 x=0 y=0 width=abc height=10\n\
+                        char id=66 x=0 y=0 width=10 height=10 page=1\n\
+                        char id=67 x=5 y=5 width=10 height=10\n\
+                        char id=68 x=0 y=0 width=10 height=10\n\
+                        kerning first=65 second=66 amount=1\n\
+                        kerning first=65 second=66 amount=2\n\
+                        kerning first=65 second=69 amount=-1\n";
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+        let outcome = parse_reader_with_outcome(contents.as_bytes(), atlas_dimensions, &strict).unwrap();
+
+        assert_eq!(4, outcome.font.characters.len());
+        // Strict mode is ignored, so that every problem is found
+        assert_eq!(vec![ParseWarning::InvalidParameter { line_number: 2, parameter: "width=abc".to_string() },
+                        ParseWarning::MissingPage { char_id: Some(66), page: 1 },
+                        ParseWarning::KerningConflict(KerningPair{ first: 65, second: 66, amount: 1 }),
+                        ParseWarning::DanglingKerning(KerningPair{ first: 65, second: 69, amount: -1 }),
+                        ParseWarning::Overlap(GlyphOverlap{ first: 2, second: 3 })], outcome.warnings);
+        assert!(!outcome.is_clean());
+
+        // Files that are not fonts are still errors
+        assert!(matches!(parse_reader_with_outcome("".as_bytes(), atlas_dimensions, &ParseOptions::default()), Err(ParseError::NotABmFont)));
+    }
+}
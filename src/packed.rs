@@ -0,0 +1,241 @@
+use crate::{CharacterInfo, Font, FractionalMetrics, Uv};
+
+const ID: usize = 0;
+const X: usize = 1;
+const Y: usize = 2;
+const WIDTH: usize = 3;
+const HEIGHT: usize = 4;
+const X_OFFSET: usize = 5;
+const Y_OFFSET: usize = 6;
+const X_ADVANCE: usize = 7;
+const PAGE: usize = 8;
+const CHNL: usize = 9;
+const ROT: usize = 10;
+// The fractional metrics are stored as the bits of their floats
+const FRACTIONAL_X_OFFSET: usize = 11;
+const FRACTIONAL_Y_OFFSET: usize = 12;
+const FRACTIONAL_X_ADVANCE: usize = 13;
+const VALUE_COUNT: usize = 14;
+/// The bit set if the character has fractional metrics, even if none of their values are known
+const HAS_FRACTIONAL_BIT: u16 = 1 << VALUE_COUNT;
+
+/// The same information as a CharacterInfo, including its fractional metrics, stored in less
+/// memory for fonts with a very large number of characters. The values are plain integers, with a
+/// bit for each of them that says if the font file had a value, instead of each value being an Option
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedCharacterInfo
+{
+    values: [i32; VALUE_COUNT],
+    valid: u16,
+    pub texture_coordinates: [Uv; 4],
+}
+
+impl PackedCharacterInfo
+{
+    pub fn id(&self) -> Option<i32>
+    {
+        self.value(ID)
+    }
+
+    pub fn x(&self) -> Option<i32>
+    {
+        self.value(X)
+    }
+
+    pub fn y(&self) -> Option<i32>
+    {
+        self.value(Y)
+    }
+
+    pub fn width(&self) -> Option<i32>
+    {
+        self.value(WIDTH)
+    }
+
+    pub fn height(&self) -> Option<i32>
+    {
+        self.value(HEIGHT)
+    }
+
+    pub fn x_offset(&self) -> Option<i32>
+    {
+        self.value(X_OFFSET)
+    }
+
+    pub fn y_offset(&self) -> Option<i32>
+    {
+        self.value(Y_OFFSET)
+    }
+
+    pub fn x_advance(&self) -> Option<i32>
+    {
+        self.value(X_ADVANCE)
+    }
+
+    pub fn page(&self) -> Option<i32>
+    {
+        self.value(PAGE)
+    }
+
+    pub fn chnl(&self) -> Option<i32>
+    {
+        self.value(CHNL)
+    }
+
+    pub fn rot(&self) -> Option<i32>
+    {
+        self.value(ROT)
+    }
+
+    /// Finds the offsets and advance that were read with a fraction, if the character has any
+    pub fn fractional(&self) -> Option<FractionalMetrics>
+    {
+        if self.valid & HAS_FRACTIONAL_BIT == 0
+        {
+            return None;
+        }
+
+        let float_value = |index| self.value(index).map(|x| f32::from_bits(x as u32));
+        Some(FractionalMetrics
+        {
+            x_offset: float_value(FRACTIONAL_X_OFFSET),
+            y_offset: float_value(FRACTIONAL_Y_OFFSET),
+            x_advance: float_value(FRACTIONAL_X_ADVANCE),
+        })
+    }
+
+    /// Finds the value at the given index, if the font file had a value for it
+    ///
+    /// `index` - the position of the value, such as WIDTH
+    fn value(&self, index: usize) -> Option<i32>
+    {
+        if self.valid & (1 << index) != 0
+        {
+            Some(self.values[index])
+        }
+        else
+        {
+            None
+        }
+    }
+}
+
+impl From<&CharacterInfo> for PackedCharacterInfo
+{
+    fn from(char_info: &CharacterInfo) -> PackedCharacterInfo
+    {
+        let fractional = char_info.fractional.unwrap_or_default();
+        let float_bits = |value: Option<f32>| value.map(|x| x.to_bits() as i32);
+
+        // Same order as the index constants
+        let options = [char_info.id, char_info.x, char_info.y, char_info.width, char_info.height, char_info.x_offset,
+                       char_info.y_offset, char_info.x_advance, char_info.page, char_info.chnl,
+                       char_info.rot, float_bits(fractional.x_offset), float_bits(fractional.y_offset),
+                       float_bits(fractional.x_advance)];
+
+        let mut packed = PackedCharacterInfo { values: [0; VALUE_COUNT], valid: 0, texture_coordinates: char_info.texture_coordinates };
+
+        for (index, value) in options.into_iter().enumerate()
+        {
+            if let Some(value) = value
+            {
+                packed.values[index] = value;
+                packed.valid |= 1 << index;
+            }
+        }
+
+        if char_info.fractional.is_some()
+        {
+            packed.valid |= HAS_FRACTIONAL_BIT;
+        }
+
+        packed
+    }
+}
+
+impl From<&PackedCharacterInfo> for CharacterInfo
+{
+    fn from(packed: &PackedCharacterInfo) -> CharacterInfo
+    {
+        CharacterInfo
+        {
+            id: packed.id(),
+            x: packed.x(),
+            y: packed.y(),
+            width: packed.width(),
+            height: packed.height(),
+            x_offset: packed.x_offset(),
+            y_offset: packed.y_offset(),
+            x_advance: packed.x_advance(),
+            page: packed.page(),
+            chnl: packed.chnl(),
+            rot: packed.rot(),
+            texture_coordinates: packed.texture_coordinates,
+            fractional: packed.fractional(),
+        }
+    }
+}
+
+impl Font
+{
+    /// Creates the packed version of each character, in the same order as the characters. The
+    /// characters of the font can then be dropped to keep only the smaller representation
+    pub fn packed_characters(&self) -> Vec<PackedCharacterInfo>
+    {
+        self.characters.iter().map(PackedCharacterInfo::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use std::mem::size_of;
+    use crate::{AtlasDimensions, CharacterInfo, FractionalMetrics, PackedCharacterInfo, parse_file, parse_reader_with_options, ParseOptions};
+
+    #[test]
+    fn check_packed_round_trip()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+
+        for file_name in ["validFormat.fnt", "missing_parameters.fnt"]
+        {
+            let test_file = env::current_dir().unwrap().join("test_files").join(file_name);
+            let font = parse_file(test_file, atlas_dimensions).unwrap();
+
+            let packed = font.packed_characters();
+            assert_eq!(font.characters.len(), packed.len());
+
+            for (char_info, packed_info) in font.characters.iter().zip(&packed)
+            {
+                assert_eq!(char_info.width, packed_info.width());
+                assert_eq!(*char_info, CharacterInfo::from(packed_info));
+            }
+        }
+    }
+
+    #[test]
+    fn check_packed_fractional_metrics()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10 xoffset=-0.75 yoffset=2 xadvance=12.5\n\
+                        char id=66 x=0 y=0 width=10 height=10 xoffset=1 yoffset=2 xadvance=9\n";
+        let options = ParseOptions{ fractional_values: true, ..ParseOptions::default() };
+        let font = parse_reader_with_options(contents.as_bytes(), AtlasDimensions{ width: 512, height: 512 }, &options).unwrap();
+        let packed = font.packed_characters();
+
+        // The fractions are kept, so that unpacking does not lose the subpixel advances
+        assert_eq!(Some(FractionalMetrics{ x_offset: Some(-0.75), y_offset: None, x_advance: Some(12.5) }), packed[0].fractional());
+        assert_eq!(None, packed[1].fractional());
+        assert_eq!(font.characters, packed.iter().map(CharacterInfo::from).collect::<Vec<CharacterInfo>>());
+
+        let mut char_info = font.characters[1].clone();
+        char_info.fractional = Some(FractionalMetrics::default());
+        assert_eq!(char_info, CharacterInfo::from(&PackedCharacterInfo::from(&char_info)));
+    }
+
+    #[test]
+    fn check_packed_size()
+    {
+        assert!(size_of::<PackedCharacterInfo>() < size_of::<CharacterInfo>());
+    }
+}
@@ -0,0 +1,6 @@
+pub use crate::{AtlasDimensions, CharacterInfo, CommonInfo, DistanceFieldInfo, Font, FontInfo, KerningPair, PageInfo, ParseError, ParseOptions, ParseOutcome, ParseWarning, PixelPos, Uv};
+pub use crate::{Alignment, Direction, LayoutOptions, PositionedGlyph, TabWidth, VerticalAnchor, Whitespace};
+pub use crate::{extract_characters, parse_binary, parse_bytes, parse_bytes_with_options, parse_file, parse_file_with_options, parse_reader, parse_reader_with_options, parse_str, parse_xml};
+pub use crate::{parse_file_with_diagnostics, parse_file_with_outcome, parse_reader_with_diagnostics, parse_reader_with_outcome};
+#[cfg(feature = "async")]
+pub use crate::parse_async;
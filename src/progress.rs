@@ -0,0 +1,135 @@
+use std::fmt::Debug;
+use std::io::{self, BufRead, Read};
+use std::path::Path;
+use crate::{decode_and_parse_with, read_file, AtlasDimensions, Font, ParseError, ParseOptions, TextReaderAdapter};
+
+/// How many times progress is reported while a font is read, not counting the report when it is done
+const PROGRESS_STEPS: u64 = 100;
+
+/// Reads all of the information of the passed in font file that is supported by this library, the
+/// same as parse_file_with_options, while reporting how much of the file has been read. This is
+/// for showing the progress of very large fonts, such as those with thousands of CJK characters,
+/// on a loading screen. The progress is the fraction of the file that has been read, from 0 to 1,
+/// and is reported each time another hundredth of the file is read. It is reported as 1 once the
+/// font has been read, but not if reading the font failed. Binary and XML fonts are read all at
+/// once, and so only report when they are done
+///
+/// `file_location` - the location of the file in the angel file format
+/// `options` - how to handle the parts of the file that do not follow the expected format
+/// `progress` - called with the fraction of the file that has been read
+pub fn parse_file_with_progress<A, F>(file_location: A, atlas_dimensions: AtlasDimensions, options: &ParseOptions, progress: F) -> Result<Font, ParseError>
+    where A: AsRef<Path> + Debug + Clone, F: FnMut(f32)
+{
+    let data = read_file(file_location)?;
+
+    let mut progress = Progress { progress, done: false };
+    let font = decode_and_parse_with(&data, atlas_dimensions, options, &mut Vec::new(), &mut progress)?;
+
+    // The last step was already reported if it ended exactly at the end of the file
+    if !progress.done
+    {
+        (progress.progress)(1.0);
+    }
+
+    Ok(font)
+}
+
+/// Wraps the lines of text fonts so that the progress is reported as they are read
+struct Progress<F>
+{
+    progress: F,
+    /// If the progress was reported for every byte of the font
+    done: bool,
+}
+
+impl<F: FnMut(f32)> TextReaderAdapter for Progress<F>
+{
+    fn wrap<'a>(&'a mut self, contents: &'a [u8]) -> impl BufRead + 'a
+    {
+        ProgressReader { reader: contents, read_bytes: 0, reported_bytes: 0, total_bytes: contents.len() as u64, progress: self }
+    }
+}
+
+/// Passes through the bytes of another reader, reporting the fraction of them that has been read
+struct ProgressReader<'p, R, F>
+{
+    reader: R,
+    read_bytes: u64,
+    reported_bytes: u64,
+    total_bytes: u64,
+    progress: &'p mut Progress<F>,
+}
+
+impl<R, F: FnMut(f32)> ProgressReader<'_, R, F>
+{
+    /// Adds the bytes that were read, reporting the progress if enough were read since the last report
+    fn advance(&mut self, count: usize)
+    {
+        self.read_bytes += count as u64;
+
+        if self.read_bytes > self.reported_bytes && (self.read_bytes - self.reported_bytes) * PROGRESS_STEPS >= self.total_bytes
+        {
+            self.reported_bytes = self.read_bytes;
+            self.progress.done = self.reported_bytes == self.total_bytes;
+            (self.progress.progress)(self.read_bytes as f32 / self.total_bytes as f32);
+        }
+    }
+}
+
+impl<R: Read, F: FnMut(f32)> Read for ProgressReader<'_, R, F>
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+    {
+        let count = self.reader.read(buf)?;
+        self.advance(count);
+        Ok(count)
+    }
+}
+
+impl<R: BufRead, F: FnMut(f32)> BufRead for ProgressReader<'_, R, F>
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]>
+    {
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize)
+    {
+        self.reader.consume(amt);
+        self.advance(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use std::fs;
+    use crate::{AtlasDimensions, parse_file, parse_file_with_progress, ParseError, ParseOptions};
+
+    #[test]
+    fn check_parse_file_with_progress()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+
+        let mut reported = Vec::new();
+        let font = parse_file_with_progress(&test_file, atlas_dimensions, &ParseOptions::default(), |x| reported.push(x)).unwrap();
+        assert_eq!(parse_file(&test_file, atlas_dimensions).unwrap(), font);
+
+        // Each line is more than a hundredth of the small file, so every line is reported
+        assert_eq!(fs::read_to_string(&test_file).unwrap().lines().count(), reported.len());
+        assert!(reported.windows(2).all(|x| x[0] < x[1]));
+        assert_eq!(Some(&1.0), reported.last());
+
+        // Fonts that are not read line by line only report when they are done
+        let mut reported = Vec::new();
+        parse_file_with_progress(test_file.with_extension("xml"), atlas_dimensions, &ParseOptions::default(), |x| reported.push(x)).unwrap();
+        assert_eq!(vec![1.0], reported);
+
+        let mut reported = Vec::new();
+        let missing_file = env::current_dir().unwrap().join("test_files").join("does_not_exist.fnt");
+        assert!(matches!(parse_file_with_progress(missing_file, atlas_dimensions, &ParseOptions::default(), |x| reported.push(x)), Err(ParseError::FileOpen { .. })));
+        assert!(reported.is_empty());
+    }
+}
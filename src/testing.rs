@@ -0,0 +1,119 @@
+use crate::{rect_texture_coords, AtlasDimensions, RectEdges, Uv};
+
+/// The width and height in pixels of the area of each character of a synthetic font
+const SYNTHETIC_GLYPH_SIZE: i32 = 16;
+
+/// How many characters of a synthetic font are placed side by side in each row of the atlas
+const SYNTHETIC_GLYPHS_PER_ROW: i32 = 32;
+
+/// Finds the texture coordinates a character with the given area of the atlas is expected to have,
+/// using the same calculation as when a font is read with the default half-open character areas.
+/// The coordinates are in the same order as the texture coordinates of a character
+///
+/// `x` - the left side of the character in the atlas, in pixels
+/// `y` - the top side of the character in the atlas, in pixels with y increasing downward
+/// `width` - the width of the character in pixels
+/// `height` - the height of the character in pixels
+pub fn expected_tex_coords(x: i32, y: i32, width: i32, height: i32, atlas_dimensions: AtlasDimensions) -> [Uv; 4]
+{
+    rect_texture_coords(x, y, width, height, atlas_dimensions, RectEdges::HalfOpen)
+}
+
+/// Creates the contents of a font file in the text version of the angel file format with the given
+/// number of characters, for testing and benchmarking how the library handles fonts of any size.
+/// The characters have ids counting up from 32, are each 16 by 16 pixels and are placed in rows
+/// of 32 that do not overlap. The atlas is 512 pixels wide and as tall as the rows, which is the
+/// size given by the scaleW and scaleH of the common line. If kerning is wanted, every character
+/// is kerned by -1 with the character after it
+///
+/// `glyph_count` - how many characters the font has
+/// `kerning` - if the font has kerning pairs
+pub fn synthetic_font(glyph_count: usize, kerning: bool) -> String
+{
+    let ids = (0..glyph_count as i32).map(|x| x + 32);
+    let rows = (glyph_count as i32 + SYNTHETIC_GLYPHS_PER_ROW - 1) / SYNTHETIC_GLYPHS_PER_ROW;
+    let atlas_height = rows.max(1) * SYNTHETIC_GLYPH_SIZE;
+
+    let mut contents = "info face=\"Synthetic\" size=16 bold=0 italic=0 charset=\"\" unicode=1 stretchH=100 smooth=1 aa=1 padding=0,0,0,0 spacing=0,0\n".to_string();
+    contents.push_str(&format!("common lineHeight=20 base=16 scaleW={} scaleH={} pages=1 packed=0\n", SYNTHETIC_GLYPHS_PER_ROW * SYNTHETIC_GLYPH_SIZE, atlas_height));
+    contents.push_str("page id=0 file=\"synthetic.png\"\n");
+    contents.push_str(&format!("chars count={}\n", glyph_count));
+
+    for (index, id) in ids.clone().enumerate()
+    {
+        let x = index as i32 % SYNTHETIC_GLYPHS_PER_ROW * SYNTHETIC_GLYPH_SIZE;
+        let y = index as i32 / SYNTHETIC_GLYPHS_PER_ROW * SYNTHETIC_GLYPH_SIZE;
+        contents.push_str(&format!("char id={} x={} y={} width={} height={} xoffset=0 yoffset=0 xadvance={} page=0 chnl=15\n",
+                                   id, x, y, SYNTHETIC_GLYPH_SIZE, SYNTHETIC_GLYPH_SIZE, SYNTHETIC_GLYPH_SIZE));
+    }
+
+    if kerning && glyph_count > 1
+    {
+        contents.push_str(&format!("kernings count={}\n", glyph_count - 1));
+
+        for id in ids.take(glyph_count - 1)
+        {
+            contents.push_str(&format!("kerning first={} second={} amount=-1\n", id, id + 1));
+        }
+    }
+
+    contents
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use proptest::prelude::*;
+    use crate::{AtlasDimensions, parse_file, parse_str, Uv};
+    use crate::testing::{expected_tex_coords, synthetic_font};
+
+    #[test]
+    fn check_expected_tex_coords()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 256 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, atlas_dimensions).unwrap();
+
+        assert_eq!(expected_tex_coords(0, 0, 22, 72, atlas_dimensions), font.characters[0].texture_coordinates);
+        assert_eq!(expected_tex_coords(256, 256, 22, 72, atlas_dimensions), font.characters[2].texture_coordinates);
+
+        // In the order of bottom left, top left, top right and bottom right
+        assert_eq!([Uv(0.0, 0.5), Uv(0.0, 1.0), Uv(0.5, 1.0), Uv(0.5, 0.5)], expected_tex_coords(0, 0, 256, 128, atlas_dimensions));
+    }
+
+    #[test]
+    fn check_synthetic_font()
+    {
+        let contents = synthetic_font(40, true);
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 32 };
+        let font = parse_str(&contents, atlas_dimensions).unwrap();
+
+        assert_eq!(Some(atlas_dimensions), font.common.atlas_dimensions());
+        assert_eq!(40, font.characters.len());
+        assert_eq!(39, font.kernings.len());
+        assert_eq!(-1, font.kerning_amount(32, 33));
+
+        // The 33rd character starts the second row
+        assert_eq!(expected_tex_coords(0, 16, 16, 16, atlas_dimensions), font.glyph(64).unwrap().texture_coordinates);
+
+        assert!(parse_str(&synthetic_font(40, false), atlas_dimensions).unwrap().kernings.is_empty());
+        assert!(parse_str(&synthetic_font(0, true), atlas_dimensions).unwrap().characters.is_empty());
+    }
+
+    proptest!
+    {
+        #[test]
+        fn check_synthetic_fonts_are_valid(glyph_count in 0_usize..500, kerning in any::<bool>())
+        {
+            let contents = synthetic_font(glyph_count, kerning);
+            let font = parse_str(&contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+            let atlas_dimensions = font.common.atlas_dimensions().unwrap();
+
+            prop_assert_eq!(glyph_count, font.characters.len());
+            prop_assert_eq!(if kerning { glyph_count.saturating_sub(1) } else { 0 }, font.kernings.len());
+            prop_assert!(font.find_overlaps().is_empty());
+            prop_assert!(font.characters.iter().all(|x| x.y.unwrap() + x.height.unwrap() <= atlas_dimensions.height));
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::HashSet;
+use crate::{AtlasDimensions, CharacterInfo, Font, KerningPair, PageInfo, ParseError};
+
+/// The channels of the chnl parameter, for characters that use all of the channels
+const ALL_CHANNELS: i32 = 15;
+
+/// Two characters whose areas of the texture atlas overlap on the same page and channel, which
+/// means one of them was drawn over the other when the atlas was packed
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GlyphOverlap
+{
+    /// The index into the characters of the font of the character listed first
+    pub first: usize,
+    /// The index into the characters of the font of the character listed second
+    pub second: usize,
+}
+
+impl Font
+{
+    /// Finds all of the characters whose areas of the texture atlas overlap. Characters that share
+    /// an area but are stored in different channels, as done by packed fonts, do not overlap.
+    /// Characters without an area, such as the space, never overlap
+    pub fn find_overlaps(&self) -> Vec<GlyphOverlap>
+    {
+        let mut rects = self.characters.iter().enumerate().filter_map(|(index, char_info)| GlyphRect::new(index, char_info)).collect::<Vec<GlyphRect>>();
+        rects.sort_by_key(|x| (x.page, x.left, x.index));
+
+        let mut overlaps = Vec::new();
+
+        for (position, rect) in rects.iter().enumerate()
+        {
+            // Sorted by the left side, only the following rects that start before this one ends
+            // could overlap it
+            for other in rects[position + 1..].iter().take_while(|x| x.page == rect.page && x.left < rect.right)
+            {
+                if rect.overlaps(other)
+                {
+                    overlaps.push(GlyphOverlap { first: rect.index.min(other.index), second: rect.index.max(other.index) });
+                }
+            }
+        }
+
+        overlaps.sort_by_key(|x| (x.first, x.second));
+        overlaps
+    }
+
+    /// Finds the pairs of characters whose areas of the texture atlas touch without overlapping, on
+    /// the same page and channel. Without any pixels between them, sampling the edge of one of the
+    /// characters can blend in the pixels of the other, which shows up as seams when rendering and
+    /// is fixed by packing the atlas with padding. Characters that only touch at a corner are
+    /// included. Each pair is listed by the ids of the characters, smallest first, and the pairs
+    /// are sorted. Characters without an id are not listed
+    pub fn find_adjacent_glyphs(&self) -> Vec<(i32, i32)>
+    {
+        let mut rects = self.characters.iter().enumerate().filter_map(|(index, char_info)| GlyphRect::new(index, char_info)).collect::<Vec<GlyphRect>>();
+        rects.sort_by_key(|x| (x.page, x.left, x.index));
+
+        let mut adjacent_glyphs = Vec::new();
+
+        for (position, rect) in rects.iter().enumerate()
+        {
+            // A rect that starts where this one ends can still touch it
+            for other in rects[position + 1..].iter().take_while(|x| x.page == rect.page && x.left <= rect.right)
+            {
+                if let (true, Some(first), Some(second)) = (rect.touches(other), self.characters[rect.index].id, self.characters[other.index].id)
+                {
+                    adjacent_glyphs.push((first.min(second), first.max(second)));
+                }
+            }
+        }
+
+        adjacent_glyphs.sort_unstable();
+        adjacent_glyphs
+    }
+
+    /// Finds the fraction of the texture atlas covered by the characters, to see how efficiently
+    /// the atlas was packed. Fonts with more than one page are compared against the area of all of
+    /// the pages. Characters that overlap are counted more than once
+    ///
+    /// `atlas_dimensions` - the size of each page of the texture atlas
+    pub fn coverage(&self, atlas_dimensions: AtlasDimensions) -> f32
+    {
+        if atlas_dimensions.width <= 0 || atlas_dimensions.height <= 0
+        {
+            return 0.0;
+        }
+
+        let covered_area = self.characters.iter()
+            .filter_map(|x| Some((x.width?.max(0) as f64) * (x.height?.max(0) as f64)))
+            .sum::<f64>();
+        let atlas_area = atlas_dimensions.width as f64 * atlas_dimensions.height as f64 * self.page_count().max(1) as f64;
+
+        (covered_area / atlas_area) as f32
+    }
+
+    /// Checks that every character is on a page that has a page line, as otherwise the texture
+    /// of the character cannot be found. Characters without a page are not checked. The error is
+    /// for the first character on a missing page; see missing_pages for all of them
+    pub fn validate_pages(&self) -> Result<(), ParseError>
+    {
+        match self.missing_pages().first()
+        {
+            Some(&(char_id, page)) => Err(ParseError::MissingPage { char_id, page }),
+            None => Ok(())
+        }
+    }
+
+    /// Lists the characters that are on a page without a page line, as the id of each character
+    /// along with its page, in the order of the characters. Characters without a page are not on
+    /// any page, and so are never listed
+    pub fn missing_pages(&self) -> Vec<(Option<i32>, i32)>
+    {
+        let page_ids = self.pages.iter().map(|x| x.id).collect::<HashSet<i32>>();
+
+        self.characters.iter()
+            .filter_map(|x| Some((x.id, x.page?)))
+            .filter(|(_, page)| !page_ids.contains(page))
+            .collect()
+    }
+
+    /// Finds the pages that at least one character is on. Characters without a page are not on any
+    /// page
+    pub fn used_pages(&self) -> HashSet<i32>
+    {
+        self.characters.iter().filter_map(|x| x.page).collect()
+    }
+
+    /// Lists the pages that have a page line but no characters, in the order of their page lines.
+    /// The textures of these pages do not have to be shipped with the font
+    pub fn unused_pages(&self) -> Vec<&PageInfo>
+    {
+        let used_pages = self.used_pages();
+        self.pages.iter().filter(|x| !used_pages.contains(&x.id)).collect()
+    }
+
+    /// Lists the kerning pairs where the first or second character is not a character of the font,
+    /// ordered by their character ids. Such pairs are never used when laying out text, and usually
+    /// mean that the font was generated with a different set of characters than its kerning
+    pub fn dangling_kernings(&self) -> Vec<KerningPair>
+    {
+        self.sorted_kernings().into_iter().filter(|x| !self.contains(x.first) || !self.contains(x.second)).collect()
+    }
+
+    /// Lists the characters that are missing any of the parameters of a char line, along with the
+    /// names of the missing parameters as written in the font file. The rot parameter is not
+    /// included as only some packers write it. Characters without an id cannot be listed
+    pub fn incomplete_glyphs(&self) -> Vec<(i32, Vec<&'static str>)>
+    {
+        self.characters.iter().filter_map(|char_info|
+            {
+                let values = [("x", char_info.x), ("y", char_info.y), ("width", char_info.width), ("height", char_info.height),
+                              ("xoffset", char_info.x_offset), ("yoffset", char_info.y_offset), ("xadvance", char_info.x_advance),
+                              ("page", char_info.page), ("chnl", char_info.chnl)];
+
+                let missing = values.iter().filter(|(_, value)| value.is_none()).map(|(field, _)| *field).collect::<Vec<&'static str>>();
+
+                if missing.is_empty()
+                {
+                    None
+                }
+                else
+                {
+                    Some((char_info.id?, missing))
+                }
+            }).collect()
+    }
+}
+
+/// The area of the texture atlas a character is in
+struct GlyphRect
+{
+    index: usize,
+    page: i32,
+    channels: i32,
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+impl GlyphRect
+{
+    /// Finds the area of the character, if it has one
+    fn new(index: usize, char_info: &CharacterInfo) -> Option<GlyphRect>
+    {
+        let (x, y, width, height) = char_info.require_rect().ok()?;
+        let (width, height) = if char_info.is_rotated() { (height, width) } else { (width, height) };
+
+        if width <= 0 || height <= 0
+        {
+            return None;
+        }
+
+        // Fonts that are not packed use zero, or leave out the channel, for characters that use
+        // all of the channels
+        let channels = match char_info.chnl.unwrap_or(0)
+        {
+            0 => ALL_CHANNELS,
+            channels => channels
+        };
+
+        Some(GlyphRect
+        {
+            index,
+            page: char_info.page.unwrap_or(0),
+            channels,
+            left: x,
+            top: y,
+            right: x.saturating_add(width),
+            bottom: y.saturating_add(height),
+        })
+    }
+
+    fn overlaps(&self, other: &GlyphRect) -> bool
+    {
+        self.page == other.page &&
+            self.channels & other.channels != 0 &&
+            self.left < other.right && other.left < self.right &&
+            self.top < other.bottom && other.top < self.bottom
+    }
+
+    /// Determines if the areas share an edge or corner, without any of their pixels overlapping
+    fn touches(&self, other: &GlyphRect) -> bool
+    {
+        self.page == other.page &&
+            self.channels & other.channels != 0 &&
+            self.left <= other.right && other.left <= self.right &&
+            self.top <= other.bottom && other.top <= self.bottom &&
+            !self.overlaps(other)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashSet;
+    use std::env;
+    use crate::{AtlasDimensions, GlyphOverlap, KerningPair, parse_file, parse_reader_with_options, parse_str, ParseError, ParseOptions};
+
+    #[test]
+    fn check_no_overlaps()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(font.find_overlaps().is_empty());
+    }
+
+    #[test]
+    fn check_validate_pages()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(font.validate_pages().is_ok());
+
+        let contents = "page id=0 file=\"first.png\"\n\
+                        page id=1 file=\"second.png\"\n\
+                        char id=65 x=0 y=0 width=10 height=10 page=1\n\
+                        char id=66 x=0 y=0 width=10 height=10\n\
+                        char id=67 x=0 y=0 width=10 height=10 page=2\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(2, font.page_count());
+
+        match font.validate_pages()
+        {
+            Err(ParseError::MissingPage { char_id, page }) => assert_eq!((Some(67), 2), (char_id, page)),
+            result => panic!("Unexpected result {:?}", result)
+        }
+
+        let contents = format!("{}char id=68 x=0 y=0 width=10 height=10 page=3\n", contents);
+        let font = parse_str(&contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(vec![(Some(67), 2), (Some(68), 3)], font.missing_pages());
+
+        // Strict parsing stops at characters on missing pages, as their textures cannot be found
+        let options = ParseOptions{ strict: true, ..ParseOptions::default() };
+        assert!(matches!(parse_reader_with_options(contents.as_bytes(), AtlasDimensions{ width: 512, height: 512 }, &options), Err(ParseError::MissingPage { char_id: Some(67), page: 2 })));
+    }
+
+    #[test]
+    fn check_unused_pages()
+    {
+        let contents = "page id=0 file=\"first.png\"\n\
+                        page id=1 file=\"second.png\"\n\
+                        page id=2 file=\"third.png\"\n\
+                        char id=65 x=0 y=0 width=10 height=10 page=2\n\
+                        char id=66 x=0 y=0 width=10 height=10 page=0\n\
+                        char id=67 x=0 y=0 width=10 height=10 page=3\n\
+                        char id=68 x=0 y=0 width=10 height=10\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // A character on a page without a page line still uses it
+        assert_eq!(HashSet::from([0, 2, 3]), font.used_pages());
+        assert_eq!(vec!["second.png"], font.unused_pages().iter().map(|x| x.file.as_str()).collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn check_dangling_kernings()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(font.dangling_kernings().is_empty());
+
+        let contents = "char id=65 x=0 y=0 width=10 height=10\n\
+                        char id=66 x=10 y=0 width=10 height=10\n\
+                        kerning first=65 second=66 amount=-1\n\
+                        kerning first=67 second=65 amount=-2\n\
+                        kerning first=66 second=68 amount=1\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(vec![KerningPair{ first: 66, second: 68, amount: 1 }, KerningPair{ first: 67, second: 65, amount: -2 }], font.dangling_kernings());
+    }
+
+    #[test]
+    fn check_incomplete_glyphs()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("missing_parameters.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // Only the space is missing its position
+        assert_eq!(vec![(32, vec!["x", "y"])], font.incomplete_glyphs());
+
+        // The character without an id is left out
+        let font = parse_str("char id=65 x=0 y=0 width=10 height=10\nchar id=abc x=1", AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert_eq!(vec![(65, vec!["xoffset", "yoffset", "xadvance", "page", "chnl"])], font.incomplete_glyphs());
+    }
+
+    #[test]
+    fn check_coverage()
+    {
+        let contents = "page id=0 file=\"first.png\"\n\
+                        char id=65 x=0 y=0 width=64 height=32\n\
+                        char id=66 x=64 y=0 width=32 height=32\n\
+                        char id=32 x=0 y=0 width=0 height=0\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 128, height: 128 }).unwrap();
+
+        assert_eq!(0.1875, font.coverage(AtlasDimensions{ width: 128, height: 128 }));
+        assert_eq!(0.0, font.coverage(AtlasDimensions{ width: 0, height: 128 }));
+
+        let two_pages = format!("{}page id=1 file=\"second.png\"\n", contents);
+        let font = parse_str(&two_pages, AtlasDimensions{ width: 128, height: 128 }).unwrap();
+        assert_eq!(0.09375, font.coverage(AtlasDimensions{ width: 128, height: 128 }));
+    }
+
+    #[test]
+    fn check_overlaps()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10 page=0 chnl=15\n\
+                        char id=66 x=5 y=5 width=10 height=10 page=0 chnl=15\n\
+                        char id=67 x=5 y=5 width=10 height=10 page=1 chnl=15\n\
+                        char id=68 x=10 y=0 width=10 height=5 page=0 chnl=15\n\
+                        char id=69 x=40 y=0 width=10 height=10 page=0 chnl=1\n\
+                        char id=70 x=40 y=0 width=10 height=10 page=0 chnl=2\n\
+                        char id=71 x=45 y=5 width=10 height=10 page=0 chnl=2\n\
+                        char id=72 x=42 y=2 width=0 height=0 page=0 chnl=15\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // Characters that only touch (A and D, B and D), are on different pages (B and C) or are in
+        // different channels (E and F) do not overlap
+        assert_eq!(vec![GlyphOverlap{ first: 0, second: 1 }, GlyphOverlap{ first: 5, second: 6 }], font.find_overlaps());
+    }
+
+    #[test]
+    fn check_adjacent_glyphs()
+    {
+        let contents = "char id=65 x=0 y=0 width=10 height=10\n\
+                        char id=66 x=10 y=2 width=10 height=10\n\
+                        char id=67 x=0 y=10 width=5 height=5\n\
+                        char id=68 x=20 y=12 width=5 height=5\n\
+                        char id=69 x=30 y=0 width=11 height=10\n\
+                        char id=70 x=10 y=2 width=10 height=10 page=1\n\
+                        char id=71 x=41 y=0 width=10 height=10 chnl=1\n\
+                        char id=72 x=51 y=0 width=10 height=10 chnl=2\n";
+        let font = parse_str(contents, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // A and B, A and C, and E and G share an edge, and B and D touch at a corner. B and F are on
+        // different pages, and G and H are in different channels
+        assert_eq!(vec![(65, 66), (65, 67), (66, 68), (69, 71)], font.find_adjacent_glyphs());
+
+        let test_file = env::current_dir().unwrap().join("test_files").join("validFormat.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+        assert!(font.find_adjacent_glyphs().is_empty());
+    }
+}
@@ -0,0 +1,319 @@
+use std::fmt::Display;
+use std::io::{self, Write};
+use crate::{quote, CharacterInfo, CommonInfo, Font, FontInfo, FractionalMetrics, KerningPair, PageInfo, Uv};
+
+impl Font
+{
+    /// Writes the font in the text version of the angel file format. Reading the written font back
+    /// results in the same font information. Parameters without a value are not written. Only the
+    /// font is written, not the file it was read from: the unknown lines keep their order but come
+    /// after the font lines, each kerning pair is written once with the amount that is used, and
+    /// the conflicts and stats, which describe the lines of the file that was read, are not kept
+    ///
+    /// `writer` - the destination of the font lines, such as a file
+    pub fn write_text<W: Write>(&self, mut writer: W) -> io::Result<()>
+    {
+        writeln!(writer, "{}", info_line(&self.info))?;
+        writeln!(writer, "{}", common_line(&self.common))?;
+
+        match (&self.distance_field, self.info.distance_range)
+        {
+            (Some(distance_field), _) => writeln!(writer, "distanceField fieldType={} distanceRange={}", distance_field.field_type, distance_field.distance_range)?,
+            (None, Some(distance_range)) => writeln!(writer, "distanceField distanceRange={}", distance_range)?,
+            (None, None) => {}
+        }
+
+        for page in &self.pages
+        {
+            writeln!(writer, "{}", page_line(page))?;
+        }
+
+        writeln!(writer, "chars count={}", self.characters.len())?;
+
+        for char_info in &self.characters
+        {
+            writeln!(writer, "{}", char_line(char_info))?;
+        }
+
+        // Fonts without kerning do not have the kerning section at all
+        if !self.kernings.is_empty()
+        {
+            writeln!(writer, "kernings count={}", self.kernings.len())?;
+
+            for kerning in &self.sorted_kernings()
+            {
+                writeln!(writer, "{}", kerning_line(kerning))?;
+            }
+        }
+
+        // Lines that were not understood are kept as they were, so that no information is lost
+        for line in &self.unknown_lines
+        {
+            writeln!(writer, "{}", line)?;
+        }
+
+        writer.flush()
+    }
+
+    /// Creates Rust source code with the characters and kerning pairs of the font as constants,
+    /// named GLYPHS and KERNINGS. The source can be written to a file by a build script and added to
+    /// a program with include!, so that a font that never changes does not have to be parsed when
+    /// the program runs. CharacterInfo, KerningPair and Uv must be imported where the source is included, as well as
+    /// FractionalMetrics if any of the characters have fractional values
+    pub fn to_rust_source(&self) -> String
+    {
+        let mut source = "// Generated by angel_font_file_parser from a font file\n\n".to_string();
+
+        source.push_str(&format!("pub const GLYPHS: [CharacterInfo; {}] =\n[\n", self.characters.len()));
+        for char_info in &self.characters
+        {
+            source.push_str(&format!("    {},\n", char_source(char_info)));
+        }
+        source.push_str("];\n\n");
+
+        let kernings = self.sorted_kernings();
+        source.push_str(&format!("pub const KERNINGS: [KerningPair; {}] =\n[\n", kernings.len()));
+        for kerning in &kernings
+        {
+            source.push_str(&format!("    KerningPair {{ first: {}, second: {}, amount: {} }},\n", kerning.first, kerning.second, kerning.amount));
+        }
+        source.push_str("];\n");
+
+        source
+    }
+}
+
+/// Creates the Rust expression that constructs the given character
+fn char_source(char_info: &CharacterInfo) -> String
+{
+    // The debug format of the floats has enough digits to be read back as exactly the same value
+    let texture_coordinates = char_info.texture_coordinates.iter().map(|Uv(u, v)| format!("Uv({:?}, {:?})", u, v)).collect::<Vec<String>>().join(", ");
+
+    let fractional = match char_info.fractional
+    {
+        Some(FractionalMetrics { x_offset, y_offset, x_advance }) => format!("Some(FractionalMetrics {{ x_offset: {:?}, y_offset: {:?}, x_advance: {:?} }})", x_offset, y_offset, x_advance),
+        None => "None".to_string()
+    };
+
+    format!("CharacterInfo {{ id: {:?}, x: {:?}, y: {:?}, width: {:?}, height: {:?}, x_offset: {:?}, y_offset: {:?}, x_advance: {:?}, page: {:?}, chnl: {:?}, rot: {:?}, texture_coordinates: [{}], fractional: {} }}",
+            char_info.id, char_info.x, char_info.y, char_info.width, char_info.height, char_info.x_offset, char_info.y_offset,
+            char_info.x_advance, char_info.page, char_info.chnl, char_info.rot, texture_coordinates, fractional)
+}
+
+/// Creates the info line of the given font information
+fn info_line(font_info: &FontInfo) -> String
+{
+    let mut line = "info".to_string();
+
+    push_parameter(&mut line, "face", font_info.face.as_deref().map(quote));
+    push_parameter(&mut line, "size", font_info.size);
+    push_parameter(&mut line, "bold", font_info.bold.map(i32::from));
+    push_parameter(&mut line, "italic", font_info.italic.map(i32::from));
+    push_parameter(&mut line, "charset", font_info.charset.as_deref().map(quote));
+    push_parameter(&mut line, "unicode", font_info.unicode.map(i32::from));
+    push_parameter(&mut line, "stretchH", font_info.stretch_h);
+    push_parameter(&mut line, "smooth", font_info.smooth.map(i32::from));
+    push_parameter(&mut line, "aa", font_info.aa);
+    push_parameter(&mut line, "padding", font_info.padding.map(|x| join_list(&x)));
+    push_parameter(&mut line, "spacing", font_info.spacing.map(|x| join_list(&x)));
+    push_parameter(&mut line, "outline", font_info.outline);
+
+    line
+}
+
+/// Creates the common line of the given common information
+fn common_line(common_info: &CommonInfo) -> String
+{
+    let mut line = "common".to_string();
+
+    push_parameter(&mut line, "lineHeight", common_info.line_height);
+    push_parameter(&mut line, "base", common_info.base);
+    push_parameter(&mut line, "scaleW", common_info.scale_w);
+    push_parameter(&mut line, "scaleH", common_info.scale_h);
+    push_parameter(&mut line, "pages", common_info.pages);
+    push_parameter(&mut line, "packed", common_info.packed);
+    push_parameter(&mut line, "alphaChnl", common_info.alpha_chnl);
+    push_parameter(&mut line, "redChnl", common_info.red_chnl);
+    push_parameter(&mut line, "greenChnl", common_info.green_chnl);
+    push_parameter(&mut line, "blueChnl", common_info.blue_chnl);
+
+    line
+}
+
+/// Creates the page line of the given page
+fn page_line(page: &PageInfo) -> String
+{
+    format!("page id={} file={}", page.id, quote(&page.file))
+}
+
+/// Creates the char line of the given character. The id is always written first, as otherwise the
+/// line is not recognized as a character when it is read back
+fn char_line(char_info: &CharacterInfo) -> String
+{
+    let mut line = "char".to_string();
+
+    push_parameter(&mut line, "id", char_info.id);
+    push_parameter(&mut line, "x", char_info.x);
+    push_parameter(&mut line, "y", char_info.y);
+    push_parameter(&mut line, "width", char_info.width);
+    push_parameter(&mut line, "height", char_info.height);
+    // Fractional values are written as they were read, so that they are not lost to rounding
+    let fractional = char_info.fractional.unwrap_or_default();
+    push_metric(&mut line, "xoffset", fractional.x_offset, char_info.x_offset);
+    push_metric(&mut line, "yoffset", fractional.y_offset, char_info.y_offset);
+    push_metric(&mut line, "xadvance", fractional.x_advance, char_info.x_advance);
+    push_parameter(&mut line, "page", char_info.page);
+    push_parameter(&mut line, "chnl", char_info.chnl);
+    push_parameter(&mut line, "rot", char_info.rot);
+
+    line
+}
+
+/// Creates the kerning line of the given kerning pair
+fn kerning_line(kerning: &KerningPair) -> String
+{
+    format!("kerning first={} second={} amount={}", kerning.first, kerning.second, kerning.amount)
+}
+
+/// Adds the parameter to the end of the line if it has a value
+fn push_parameter<T: Display>(line: &mut String, identifier: &str, value: Option<T>)
+{
+    if let Some(value) = value
+    {
+        line.push_str(&format!(" {}={}", identifier, value));
+    }
+}
+
+/// Adds the offset or advance to the end of the line, writing its fractional value if it has one
+fn push_metric(line: &mut String, identifier: &str, fractional: Option<f32>, value: Option<i32>)
+{
+    match fractional
+    {
+        Some(fractional) => push_parameter(line, identifier, Some(fractional)),
+        None => push_parameter(line, identifier, value)
+    }
+}
+
+/// Converts the list into its comma separated form
+fn join_list(list: &[i32]) -> String
+{
+    list.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(",")
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use std::io::Cursor;
+    use crate::{AtlasDimensions, CharacterInfo, KerningPair, parse_file, parse_reader, Uv};
+
+    include!("../test_files/kerning_glyphs.rs");
+
+    #[test]
+    fn check_text_round_trip()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+
+        for file_name in ["validFormat.fnt", "kerning.fnt", "missing_parameters.fnt", "distance_field.fnt", "unrecognized_line.fnt"]
+        {
+            let test_file = env::current_dir().unwrap().join("test_files").join(file_name);
+            let font = parse_file(test_file, atlas_dimensions).unwrap();
+
+            let mut written = Vec::new();
+            font.write_text(&mut written).unwrap();
+
+            let read_back = parse_reader(Cursor::new(written), atlas_dimensions).unwrap();
+            assert_eq!(font, read_back);
+        }
+    }
+
+    #[test]
+    fn check_written_file_information()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let contents = "info face=\"Arial\" size=57\n\
+                        extension first=1\n\
+                        char id=65 x=0 y=0 width=10 height=10\n\
+                        extension second=2\n\
+                        char id=66 x=10 y=0 width=10 height=10\n\
+                        kerning first=65 second=66 amount=-1\n\
+                        kerning first=65 second=66 amount=-2\n\
+                        kerning first=65 second=66 amount=-2\n";
+        let font = parse_reader(contents.as_bytes(), atlas_dimensions).unwrap();
+
+        let mut written = Vec::new();
+        font.write_text(&mut written).unwrap();
+        let read_back = parse_reader(Cursor::new(written), atlas_dimensions).unwrap();
+
+        // The font information is the same, with the unknown lines in the same order
+        assert_eq!(font.info, read_back.info);
+        assert_eq!(font.characters, read_back.characters);
+        assert_eq!(font.kernings, read_back.kernings);
+        assert_eq!(font.unknown_lines, read_back.unknown_lines);
+
+        // The kerning pair that was listed three times is only written with the amount it has
+        assert_eq!(vec![KerningPair{ first: 65, second: 66, amount: -1 }], font.kerning_conflicts);
+        assert!(read_back.kerning_conflicts.is_empty());
+        assert_eq!((3, 1), (font.stats.kernings, read_back.stats.kernings));
+    }
+
+    #[test]
+    fn check_written_lines()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        let mut written = Vec::new();
+        font.write_text(&mut written).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        let lines = written.lines().collect::<Vec<&str>>();
+
+        assert_eq!("info face=\"Arial\" size=57 bold=0 italic=0 charset=\"\" unicode=0 stretchH=100 smooth=1 aa=1 padding=8,8,8,8 spacing=0,0", lines[0]);
+        assert_eq!("common lineHeight=82 base=53 scaleW=512 scaleH=512 pages=1 packed=0 alphaChnl=1 redChnl=0 greenChnl=0 blueChnl=0", lines[1]);
+        assert_eq!("page id=0 file=\"arial.png\"", lines[2]);
+        assert_eq!("chars count=3", lines[3]);
+        assert_eq!("char id=124 x=0 y=0 width=22 height=72 xoffset=-3 yoffset=3 xadvance=30 page=0 chnl=0", lines[4]);
+        assert_eq!("kernings count=2", lines[7]);
+        assert_eq!("kerning first=32 second=124 amount=-1", lines[8]);
+        assert_eq!(10, lines.len());
+    }
+
+    #[test]
+    fn check_escaped_round_trip()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let mut font = parse_file(test_file, atlas_dimensions).unwrap();
+        font.info.face = Some("Foo\\".to_string());
+        font.info.charset = Some("Say \"hi\" \\o/".to_string());
+
+        let mut written = Vec::new();
+        font.write_text(&mut written).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert!(written.starts_with("info face=\"Foo\\\\\" size=57 bold=0 italic=0 charset=\"Say \\\"hi\\\" \\\\o/\" unicode=0"), "{}", written);
+
+        // The face ending with a backslash does not escape its closing quote, so the rest of the
+        // info line is still read
+        let read_back = parse_reader(Cursor::new(written), atlas_dimensions).unwrap();
+        assert_eq!(font.info, read_back.info);
+
+        font.pages[0].file = "fonts\\arial\\".to_string();
+        let mut written = Vec::new();
+        font.write_text(&mut written).unwrap();
+        assert_eq!(font.pages, parse_reader(Cursor::new(written), atlas_dimensions).unwrap().pages);
+        assert_eq!("fonts\\a.png", crate::PageInfo::from_line("page id=0 file=\"fonts\\a.png\"", &mut Vec::new()).unwrap().file);
+    }
+
+    #[test]
+    fn check_rust_source()
+    {
+        let test_file = env::current_dir().unwrap().join("test_files").join("kerning.fnt");
+        let font = parse_file(test_file, AtlasDimensions{ width: 512, height: 512 }).unwrap();
+
+        // The included source was generated from the same font, and compiles back into its values
+        let source = std::fs::read_to_string(env::current_dir().unwrap().join("test_files").join("kerning_glyphs.rs")).unwrap();
+        assert_eq!(source, font.to_rust_source());
+        assert_eq!(font.characters, GLYPHS.to_vec());
+        assert_eq!(font.sorted_kernings(), KERNINGS.to_vec());
+    }
+}
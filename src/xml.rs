@@ -0,0 +1,299 @@
+use crate::{quote, read_font, AtlasDimensions, Font, ParseError, ParseOptions};
+
+/// Reads all of the information supported by this library from the contents of a font file in the
+/// XML version of the angel file format. The elements can be in any order and nesting, the same as
+/// the lines of the text version can be. Line numbers of errors are those of the XML elements
+///
+/// `contents` - the complete contents of the font file
+pub fn parse_xml(contents: &str, atlas_dimensions: AtlasDimensions) -> Result<Font, ParseError>
+{
+    read_xml(contents, atlas_dimensions, &ParseOptions::default(), &mut Vec::new())
+}
+
+/// Determines if the contents of a font file are in the XML version of the angel file format
+pub(crate) fn is_xml(contents: &str) -> bool
+{
+    let contents = contents.trim_start();
+    contents.starts_with("<?xml") || contents.starts_with("<font")
+}
+
+/// Reads a font in the XML version of the angel file format. Each element is turned into the line
+/// of the text version that has the same information, so that it is read exactly as the text
+/// version is. The line numbers of errors are then changed to those of the elements
+pub(crate) fn read_xml(contents: &str, atlas_dimensions: AtlasDimensions, options: &ParseOptions, line_errors: &mut Vec<ParseError>) -> Result<Font, ParseError>
+{
+    let mut lines = String::new();
+    // The line number of the element of each text line, as several elements can share a line
+    let mut element_line_numbers = Vec::new();
+
+    for (line_number, element) in xml_elements(contents)?
+    {
+        if let Some(line) = element_line(&element)
+        {
+            lines.push_str(&line);
+            lines.push('\n');
+            element_line_numbers.push(line_number);
+        }
+    }
+
+    let first_line_error = line_errors.len();
+    let font = read_font(lines.as_bytes(), atlas_dimensions, options, line_errors).map_err(|mut error|
+        {
+            use_element_line_number(&mut error, &element_line_numbers);
+            error
+        });
+
+    for error in &mut line_errors[first_line_error..]
+    {
+        use_element_line_number(error, &element_line_numbers);
+    }
+
+    font
+}
+
+/// Changes the line number of the error from that of the text line to that of its element
+///
+/// `element_line_numbers` - the line number of the element of each text line
+fn use_element_line_number(error: &mut ParseError, element_line_numbers: &[usize])
+{
+    match error
+    {
+        ParseError::LineRead { line_number, .. } | ParseError::InvalidParameter { line_number, .. } | ParseError::ValueOverflow { line: line_number, .. } =>
+            {
+                if let Some(element_line_number) = element_line_numbers.get(line_number.wrapping_sub(1))
+                {
+                    *line_number = *element_line_number;
+                }
+            },
+        _ => {}
+    }
+}
+
+/// An opening or empty XML element, such as <char id="32" />
+struct Element
+{
+    name: String,
+    attributes: Vec<(String, String)>,
+}
+
+/// Finds the opening and empty elements of the XML document, along with the line number each
+/// starts on. Closing tags, comments and declarations do not hold any font information
+fn xml_elements(contents: &str) -> Result<Vec<(usize, Element)>, ParseError>
+{
+    let mut elements = Vec::new();
+    let mut remaining = contents;
+    let mut line_number = 1;
+
+    while let Some(start) = remaining.find('<')
+    {
+        line_number += remaining[..start].matches('\n').count();
+        remaining = &remaining[start..];
+
+        let end_marker = if remaining.starts_with("<!--") { "-->" } else if remaining.starts_with("<?") { "?>" } else { ">" };
+        let end = match tag_end(remaining, end_marker)
+        {
+            Some(end) => end,
+            None => return Err(ParseError::InvalidXml(format!("The tag on line {} is not closed", line_number)))
+        };
+
+        let tag = &remaining[1..end];
+        remaining = &remaining[end + end_marker.len()..];
+
+        if end_marker == ">" && !tag.starts_with('/')
+        {
+            elements.push((line_number, parse_element(tag.trim_end_matches('/'))));
+        }
+
+        line_number += tag.matches('\n').count();
+    }
+
+    Ok(elements)
+}
+
+/// Finds where the tag ends, ignoring the marker inside of quoted attribute values
+///
+/// `tag` - the rest of the document, starting from the '<' of the tag
+/// `end_marker` - the characters the tag ends with
+fn tag_end(tag: &str, end_marker: &str) -> Option<usize>
+{
+    // Comments and declarations do not have attributes that could contain the end marker
+    if end_marker != ">"
+    {
+        return tag.find(end_marker);
+    }
+
+    let mut quote = None;
+
+    for (index, character) in tag.char_indices()
+    {
+        match (quote, character)
+        {
+            (None, '"' | '\'') => quote = Some(character),
+            (Some(open_quote), _) if open_quote == character => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits the inside of a tag into the name of the element and its attributes. Attributes that
+/// are not of the form name="value" are kept with an empty name, so that they are reported as
+/// invalid parameters
+///
+/// `tag` - the inside of the tag, without the angle brackets
+fn parse_element(tag: &str) -> Element
+{
+    let tag = tag.trim();
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let mut element = Element { name: tag[..name_end].to_string(), attributes: Vec::new() };
+    let mut remaining = tag[name_end..].trim_start();
+
+    while !remaining.is_empty()
+    {
+        let quoted_value = remaining.split_once('=').and_then(|(name, value)|
+            {
+                let value = value.trim_start();
+                let quote = value.chars().next().filter(|x| *x == '"' || *x == '\'')?;
+                let value_end = value[1..].find(quote)? + 1;
+                Some((name.trim(), &value[1..value_end], &value[value_end + 1..]))
+            });
+
+        match quoted_value
+        {
+            Some((name, value, rest)) if !name.contains(char::is_whitespace) =>
+                {
+                    element.attributes.push((name.to_string(), unescape(value)));
+                    remaining = rest.trim_start();
+                },
+            _ =>
+                {
+                    let part_end = remaining.find(char::is_whitespace).unwrap_or(remaining.len());
+                    element.attributes.push((String::new(), remaining[..part_end].to_string()));
+                    remaining = remaining[part_end..].trim_start();
+                }
+        }
+    }
+
+    element
+}
+
+/// Creates the line of the text version of the format that has the same information as the
+/// element. The elements that only hold other elements do not have a line
+fn element_line(element: &Element) -> Option<String>
+{
+    if element.name == "font" || element.name == "pages"
+    {
+        return None;
+    }
+
+    let mut line = element.name.clone();
+    let mut attributes = element.attributes.iter().collect::<Vec<&(String, String)>>();
+
+    // A char line is only recognized when the id comes first
+    if element.name == "char"
+    {
+        attributes.sort_by_key(|(name, _)| name != "id");
+    }
+
+    for (name, value) in attributes
+    {
+        line.push(' ');
+
+        // Values are written in quotes only if needed, as the numbers of char and common lines are
+        // read without removing quotes. Quotes and backslashes inside of the value are escaped, as
+        // in face names
+        match (name.is_empty(), value.is_empty() || value.contains(char::is_whitespace) || value.contains(['"', '\\']))
+        {
+            (true, _) => line.push_str(value),
+            (false, true) => line.push_str(&format!("{}={}", name, quote(value))),
+            (false, false) => line.push_str(&format!("{}={}", name, value)),
+        }
+    }
+
+    Some(line)
+}
+
+/// Replaces the escaped characters of an attribute value, such as &amp;, with the characters
+fn unescape(value: &str) -> String
+{
+    value.replace("&quot;", "\"").replace("&apos;", "'").replace("&lt;", "<").replace("&gt;", ">").replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::env;
+    use std::fs;
+    use crate::{AtlasDimensions, parse_file, parse_xml, ParseError, ParseOptions, read_xml};
+
+    #[test]
+    fn check_parse_xml()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_folder = env::current_dir().unwrap().join("test_files");
+        let text_font = parse_file(test_folder.join("kerning.fnt"), atlas_dimensions).unwrap();
+
+        let xml_font = parse_xml(&fs::read_to_string(test_folder.join("kerning.xml")).unwrap(), atlas_dimensions).unwrap();
+        assert_eq!(text_font.info, xml_font.info);
+        assert_eq!(text_font.common, xml_font.common);
+        assert_eq!(text_font.pages, xml_font.pages);
+        assert_eq!(text_font.characters, xml_font.characters);
+        assert_eq!(text_font.kernings, xml_font.kernings);
+
+        // Font files are detected as XML from their contents
+        assert_eq!(xml_font, parse_file(test_folder.join("kerning.xml"), atlas_dimensions).unwrap());
+    }
+
+    #[test]
+    fn check_reordered_xml()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+        let test_folder = env::current_dir().unwrap().join("test_files");
+        let in_order = parse_file(test_folder.join("kerning.xml"), atlas_dimensions).unwrap();
+
+        // The kernings come before the characters, the pages are between the characters, and the
+        // info element is last
+        let reordered = parse_file(test_folder.join("reordered.xml"), atlas_dimensions).unwrap();
+        assert_eq!(in_order.info, reordered.info);
+        assert_eq!(in_order.common, reordered.common);
+        assert_eq!(in_order.pages, reordered.pages);
+        assert_eq!(in_order.characters, reordered.characters);
+        assert_eq!(in_order.kernings, reordered.kernings);
+
+        // The distance field is kept even when it comes before the info it is part of
+        let font = parse_xml("<font><distanceField distanceRange=\"4\"/><info face=\"Arial\"/></font>", atlas_dimensions).unwrap();
+        assert_eq!(Some(4), font.info.distance_range);
+    }
+
+    #[test]
+    fn check_invalid_xml()
+    {
+        let atlas_dimensions = AtlasDimensions{ width: 512, height: 512 };
+
+        assert!(matches!(parse_xml("<font>\n<char id=\"32\"", atlas_dimensions), Err(ParseError::InvalidXml(_))));
+        assert!(matches!(parse_xml("<font></font>", atlas_dimensions), Err(ParseError::NotABmFont)));
+
+        // Line numbers are those of the elements, even after a comment over several lines
+        let contents = "<font>\n<!-- first\nsecond -->\n<char id=\"65\" x=\"abc\"/>\n</font>";
+        let strict = ParseOptions{ strict: true, ..ParseOptions::default() };
+        assert!(matches!(read_xml(contents, atlas_dimensions, &strict, &mut Vec::new()), Err(ParseError::InvalidParameter { line_number: 4, .. })));
+
+        // Elements that share a line do not move the elements on the lines after them
+        let contents = "<font><info face=\"Arial\"/><common lineHeight=\"82\"/>\n<char id=\"65\" x=\"abc\" width=\"99999999999\"/>\n</font>";
+        assert!(matches!(read_xml(contents, atlas_dimensions, &strict, &mut Vec::new()), Err(ParseError::InvalidParameter { line_number: 2, .. })));
+        let mut line_errors = Vec::new();
+        read_xml(contents, atlas_dimensions, &ParseOptions::default(), &mut line_errors).unwrap();
+        assert!(matches!(line_errors.as_slice(), [ParseError::InvalidParameter { line_number: 2, .. }, ParseError::ValueOverflow { line: 2, .. }]));
+
+        // Escaped characters and attributes out of order are read the same as in the text format
+        let font = parse_xml("<font>\n<info face=\"A &amp; B\"/>\n<char x=\"3\" id=\"65\"/>\n</font>", atlas_dimensions).unwrap();
+        assert_eq!(Some("A & B".to_string()), font.info.face);
+        assert_eq!((Some(65), Some(3)), (font.characters[0].id, font.characters[0].x));
+
+        // Quotes in a face name are kept, as they are in the text format
+        let font = parse_xml("<font><info face=\"He said &quot;hi&quot;\"/><char id=\"65\"/></font>", atlas_dimensions).unwrap();
+        assert_eq!(Some("He said \"hi\"".to_string()), font.info.face);
+    }
+}
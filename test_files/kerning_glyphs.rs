@@ -0,0 +1,14 @@
+// Generated by angel_font_file_parser from a font file
+
+pub const GLYPHS: [CharacterInfo; 3] =
+[
+    CharacterInfo { id: Some(124), x: Some(0), y: Some(0), width: Some(22), height: Some(72), x_offset: Some(-3), y_offset: Some(3), x_advance: Some(30), page: Some(0), chnl: Some(0), rot: None, texture_coordinates: [Uv(0.0, 0.859375), Uv(0.0, 1.0), Uv(0.04296875, 1.0), Uv(0.04296875, 0.859375)], fractional: None },
+    CharacterInfo { id: Some(32), x: Some(0), y: Some(0), width: Some(0), height: Some(0), x_offset: Some(0), y_offset: Some(53), x_advance: Some(32), page: Some(0), chnl: Some(0), rot: None, texture_coordinates: [Uv(0.0, 1.0), Uv(0.0, 1.0), Uv(0.0, 1.0), Uv(0.0, 1.0)], fractional: None },
+    CharacterInfo { id: Some(124), x: Some(256), y: Some(256), width: Some(22), height: Some(72), x_offset: Some(-3), y_offset: Some(3), x_advance: Some(30), page: Some(0), chnl: Some(0), rot: None, texture_coordinates: [Uv(0.5, 0.359375), Uv(0.5, 0.5), Uv(0.54296875, 0.5), Uv(0.54296875, 0.359375)], fractional: None },
+];
+
+pub const KERNINGS: [KerningPair; 2] =
+[
+    KerningPair { first: 32, second: 124, amount: -1 },
+    KerningPair { first: 124, second: 32, amount: 2 },
+];